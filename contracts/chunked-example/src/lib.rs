@@ -6,73 +6,193 @@
 #![no_std]
 
 use soroban_chonk::prelude::*;
+use soroban_render_common::bytes::bytes_from_slices;
+use soroban_render_common::query::{query_param, split_query};
 use soroban_render_sdk::prelude::*;
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, Env, String, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, String, Symbol, Vec,
+};
 
 soroban_render!(markdown);
 
+/// Comments shown before the continuation marker when no `page_size` is given
+const DEFAULT_PAGE_SIZE: u32 = 5;
+
+/// Pinned posts shown before their own continuation marker
+const PINNED_SHOWN: u32 = 2;
+
+#[contracttype]
+pub enum DataKey {
+    Moderator, // Address allowed to edit and remove comments
+}
+
+/// Get a chunk counting from the end, so index 0 is the last-pushed chunk
+fn get_rev(chonk: &Chonk, index: u32) -> Option<Bytes> {
+    let count = chonk.count();
+    if index >= count {
+        return None;
+    }
+    chonk.get(count - 1 - index)
+}
+
+/// Total chunks across several collections, e.g. pinned plus regular posts
+fn count_all(env: &Env, collections: &[Symbol]) -> u32 {
+    collections
+        .iter()
+        .map(|c| Chonk::open(env, c.clone()).count())
+        .sum()
+}
+
+/// Continuation marker that also carries the collection's chunk count and
+/// byte size, so the viewer can show "5 of 15 loaded" before fetching:
+/// `{{continue collection="comments" from=5 total=15 size=812}}`
+fn continuation_marker(env: &Env, collection: &str, from: u32, meta: &ChonkMeta) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"{{continue collection=\"");
+    out.extend_from_slice(collection.as_bytes());
+    out.extend_from_slice(b"\" from=");
+    out.append(&u32_to_bytes(env, from));
+    out.extend_from_slice(b" total=");
+    out.append(&u32_to_bytes(env, meta.count));
+    out.extend_from_slice(b" size=");
+    out.append(&u32_to_bytes(env, meta.total_bytes));
+    out.extend_from_slice(b"}}\n");
+    out
+}
+
 #[contract]
 pub struct ChunkedExampleContract;
 
 #[contractimpl]
 impl ChunkedExampleContract {
-    /// Initialize with sample comments
-    pub fn init(env: Env) {
+    /// Initialize with sample pinned posts and comments. `moderator` may
+    /// later edit or remove comments.
+    pub fn init(env: Env, moderator: Address) {
+        if env.storage().persistent().has(&DataKey::Moderator) {
+            panic!("Already initialized");
+        }
+        env.storage().persistent().set(&DataKey::Moderator, &moderator);
+
+        let pinned = Chonk::open(&env, symbol_short!("pinned"));
+        let pinned_samples = [
+            "Welcome! Please keep the discussion on topic.",
+            "Comments load progressively as you read.",
+            "Report spam to the thread owner.",
+        ];
+        for sample in pinned_samples {
+            pinned.push(bytes_from_slices(
+                &env,
+                &["> 📌 **Moderators**: ".as_bytes(), sample.as_bytes(), b"\n\n"],
+            ));
+        }
+
         let comments = Chonk::open(&env, symbol_short!("comments"));
 
-        // Add 15 sample comments as pre-formatted markdown
+        // Add 15 sample comments, quoted as pre-formatted markdown
         let samples = [
-            "> **Alice**: Great post! This is really helpful. (#0)\n\n",
-            "> **Bob**: I have a question about the implementation. (#1)\n\n",
-            "> **Carol**: Thanks for sharing this information. (#2)\n\n",
-            "> **Dave**: This is exactly what I was looking for! (#3)\n\n",
-            "> **Eve**: Interesting perspective on the topic. (#4)\n\n",
-            "> **Alice**: Could you elaborate on that point? (#5)\n\n",
-            "> **Bob**: I agree with the previous comment. (#6)\n\n",
-            "> **Carol**: This changed my understanding completely. (#7)\n\n",
-            "> **Dave**: Well explained and easy to follow. (#8)\n\n",
-            "> **Eve**: Looking forward to more content like this! (#9)\n\n",
-            "> **Alice**: Adding another thought here. (#10)\n\n",
-            "> **Bob**: This thread is getting interesting. (#11)\n\n",
-            "> **Carol**: Great discussion everyone! (#12)\n\n",
-            "> **Dave**: One more point to consider. (#13)\n\n",
-            "> **Eve**: Thanks for the insightful conversation! (#14)\n\n",
+            "**Alice**: Great post! This is really helpful. (#0)",
+            "**Bob**: I have a question about the implementation. (#1)",
+            "**Carol**: Thanks for sharing this information. (#2)",
+            "**Dave**: This is exactly what I was looking for! (#3)",
+            "**Eve**: Interesting perspective on the topic. (#4)",
+            "**Alice**: Could you elaborate on that point? (#5)",
+            "**Bob**: I agree with the previous comment. (#6)",
+            "**Carol**: This changed my understanding completely. (#7)",
+            "**Dave**: Well explained and easy to follow. (#8)",
+            "**Eve**: Looking forward to more content like this! (#9)",
+            "**Alice**: Adding another thought here. (#10)",
+            "**Bob**: This thread is getting interesting. (#11)",
+            "**Carol**: Great discussion everyone! (#12)",
+            "**Dave**: One more point to consider. (#13)",
+            "**Eve**: Thanks for the insightful conversation! (#14)",
         ];
 
         for sample in samples {
-            comments.push(Bytes::from_slice(&env, sample.as_bytes()));
+            comments.push(bytes_from_slices(&env, &[b"> ", sample.as_bytes(), b"\n\n"]));
         }
     }
 
-    /// Main render - shows first 5 comments with continuation for rest
-    pub fn render(env: Env, _path: Option<String>, _viewer: Option<Address>) -> Bytes {
+    /// Main render - shows the first pinned posts and the first page of
+    /// comments, each followed by its own continuation marker for the rest.
+    /// `/newest` renders the thread newest-first; `?page_size=N` sets how many
+    /// comments load immediately (default 5).
+    pub fn render(env: Env, path: Option<String>, viewer: Option<Address>) -> Bytes {
+        let pinned = Chonk::open(&env, symbol_short!("pinned"));
         let comments = Chonk::open(&env, symbol_short!("comments"));
         let total = comments.count();
+        let posts = count_all(&env, &[symbol_short!("pinned"), symbol_short!("comments")]);
+        let moderator: Option<Address> = env.storage().persistent().get(&DataKey::Moderator);
+        let moderating = viewer.is_some() && viewer == moderator;
 
-        const IMMEDIATE: u32 = 5;
+        let (route, query) = split_query(&env, &path_to_bytes(&env, &path));
+        let newest_first = path_eq(&route, b"/newest");
+        let page_size = query_param(&query, b"page_size")
+            .and_then(|v| bytes_to_u32(&v))
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_PAGE_SIZE);
 
         let mut builder = MarkdownBuilder::new(&env);
 
         builder = builder
             .h1("Chunked Content Demo")
             .paragraph("This thread demonstrates progressive content loading.")
-            .paragraph("The first 5 comments load immediately. The rest load progressively.")
-            .hr()
-            .h2("Comments");
+            .text("The first ")
+            .number(page_size)
+            .text(" comments load immediately. The rest load progressively.")
+            .newline()
+            .newline()
+            .render_link("Oldest first", "/")
+            .text(" | ")
+            .render_link("Newest first", "/newest")
+            .newline()
+            .newline()
+            .number(posts)
+            .text(" posts, ")
+            .number(pinned.count())
+            .text(" pinned")
+            .newline()
+            .newline()
+            .hr();
+
+        // Pinned posts come first, with a marker of their own so they load
+        // independently of the comment stream
+        if pinned.count() > 0 {
+            builder = builder.h2("Pinned");
+            for i in 0..core::cmp::min(PINNED_SHOWN, pinned.count()) {
+                if let Some(post) = pinned.get(i) {
+                    builder = builder.raw(post);
+                }
+            }
+            if pinned.count() > PINNED_SHOWN {
+                let marker = continuation_marker(&env, "pinned", PINNED_SHOWN, &pinned.meta());
+                builder = builder.raw(marker);
+            }
+        }
+
+        builder = builder.h2("Comments");
 
-        // Show first N comments immediately
-        let show = core::cmp::min(IMMEDIATE, total);
+        // Show first N comments immediately. The moderator gets a Remove
+        // action on each; progressively loaded chunks are plain content.
+        let show = core::cmp::min(page_size, total);
         for i in 0..show {
-            if let Some(comment) = comments.get(i) {
+            let index = if newest_first { total - 1 - i } else { i };
+            if let Some(comment) = comments.get(index) {
                 builder = builder.raw(comment);
+                if moderating {
+                    builder = builder
+                        .tx_link_id("Remove", "remove_comment", index)
+                        .newline()
+                        .newline();
+                }
             }
         }
 
-        // Add continuation marker if more exist
-        if total > IMMEDIATE {
+        // Add continuation marker if more exist. Newest-first reads from the
+        // reversed view so the remaining chunks count down.
+        if total > page_size {
+            let collection = if newest_first { "rcomments" } else { "comments" };
             builder = builder
                 .paragraph("---")
-                .continuation("comments", IMMEDIATE, Some(total));
+                .raw(continuation_marker(&env, collection, page_size, &comments.meta()));
         }
 
         if total == 0 {
@@ -82,13 +202,73 @@ impl ChunkedExampleContract {
         builder.hr().paragraph("*Powered by soroban-chonk*").build()
     }
 
-    /// Get a single chunk (called by viewer for progressive loading)
+    /// Replace comment `id` with moderator-supplied text (moderator only).
+    /// The comment keeps its position and the count is unchanged.
+    pub fn edit_comment(env: Env, id: u32, content: String) {
+        Self::require_moderator(&env);
+        let comments = Chonk::open(&env, symbol_short!("comments"));
+        if id >= comments.count() {
+            panic!("Comment not found");
+        }
+
+        let mut chunk = Bytes::from_slice(&env, b"> ");
+        chunk.append(&string_to_bytes(&env, &content));
+        chunk.extend_from_slice(b" *(edited by moderator)*\n\n");
+        comments.set(id, chunk);
+    }
+
+    /// Remove comment `id` (moderator only). Removal compacts the
+    /// collection: later comments shift down one index and `count` drops
+    /// by one, so continuation markers rendered before the removal may skip
+    /// a comment until the page is re-rendered.
+    pub fn remove_comment(env: Env, id: u32) {
+        Self::require_moderator(&env);
+        let comments = Chonk::open(&env, symbol_short!("comments"));
+        if comments.remove(id).is_none() {
+            panic!("Comment not found");
+        }
+    }
+
+    fn require_moderator(env: &Env) {
+        let moderator: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Moderator)
+            .expect("Not initialized");
+        moderator.require_auth();
+    }
+
+    /// Get a single chunk (called by viewer for progressive loading).
+    /// `rcomments` is a reversed view of `comments`.
     pub fn get_chunk(env: Env, collection: Symbol, index: u32) -> Option<Bytes> {
+        if collection == symbol_short!("rcomments") {
+            return get_rev(&Chonk::open(&env, symbol_short!("comments")), index);
+        }
         Chonk::open(&env, collection).get(index)
     }
 
-    /// Get chunk metadata
+    /// Get up to `count` chunks starting at `start` in one call.
+    /// The range is clamped to the collection, so reading past the end
+    /// returns a short (possibly empty) vec.
+    pub fn get_chunks(env: Env, collection: Symbol, start: u32, count: u32) -> Vec<Bytes> {
+        let mut chunks: Vec<Bytes> = Vec::new(&env);
+        for i in start..start.saturating_add(count) {
+            match Self::get_chunk(env.clone(), collection.clone(), i) {
+                Some(chunk) => chunks.push_back(chunk),
+                None => break,
+            }
+        }
+        chunks
+    }
+
+    /// Get chunk metadata. `rcomments` shares the metadata of `comments`,
+    /// since it is the same chunks in reverse order.
     pub fn get_chunk_meta(env: Env, collection: Symbol) -> Option<ChonkMeta> {
+        let collection = if collection == symbol_short!("rcomments") {
+            symbol_short!("comments")
+        } else {
+            collection
+        };
         let chonk = Chonk::open(&env, collection);
         if chonk.count() > 0 {
             Some(chonk.meta())
@@ -101,6 +281,7 @@ impl ChunkedExampleContract {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::testutils::Address as _;
     use soroban_sdk::Env;
 
     #[test]
@@ -109,7 +290,7 @@ mod tests {
         let contract_id = env.register(ChunkedExampleContract, ());
 
         env.as_contract(&contract_id, || {
-            ChunkedExampleContract::init(env.clone());
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
             let comments = Chonk::open(&env, symbol_short!("comments"));
             assert_eq!(comments.count(), 15);
         });
@@ -121,7 +302,7 @@ mod tests {
         let contract_id = env.register(ChunkedExampleContract, ());
 
         env.as_contract(&contract_id, || {
-            ChunkedExampleContract::init(env.clone());
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
             let chunk = ChunkedExampleContract::get_chunk(env.clone(), symbol_short!("comments"), 0);
             assert!(chunk.is_some());
         });
@@ -133,7 +314,7 @@ mod tests {
         let contract_id = env.register(ChunkedExampleContract, ());
 
         env.as_contract(&contract_id, || {
-            ChunkedExampleContract::init(env.clone());
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
             let result = ChunkedExampleContract::render(env.clone(), None, None);
 
             // Check that it contains continuation marker
@@ -148,4 +329,233 @@ mod tests {
             assert!(s.contains("{{continue"));
         });
     }
+
+    #[test]
+    fn test_get_rev() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+
+        env.as_contract(&contract_id, || {
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
+            let comments = Chonk::open(&env, symbol_short!("comments"));
+
+            let newest = get_rev(&comments, 0).unwrap();
+            let expected = Bytes::from_slice(
+                &env,
+                b"> **Eve**: Thanks for the insightful conversation! (#14)\n\n",
+            );
+            assert_eq!(newest, expected);
+            assert_eq!(get_rev(&comments, 14), comments.get(0));
+            assert!(get_rev(&comments, 15).is_none());
+
+            // The reversed view served to the viewer counts down
+            let chunk =
+                ChunkedExampleContract::get_chunk(env.clone(), symbol_short!("rcomments"), 5);
+            assert_eq!(chunk, comments.get(9));
+        });
+    }
+
+    #[test]
+    fn test_get_chunks() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+
+        env.as_contract(&contract_id, || {
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
+            let comments = Chonk::open(&env, symbol_short!("comments"));
+
+            let chunks =
+                ChunkedExampleContract::get_chunks(env.clone(), symbol_short!("comments"), 5, 5);
+            assert_eq!(chunks.len(), 5);
+            for i in 0..5 {
+                assert_eq!(chunks.get(i), comments.get(5 + i));
+            }
+
+            // Past the end returns a short vec rather than panicking
+            let tail =
+                ChunkedExampleContract::get_chunks(env.clone(), symbol_short!("comments"), 12, 10);
+            assert_eq!(tail.len(), 3);
+            let none =
+                ChunkedExampleContract::get_chunks(env.clone(), symbol_short!("comments"), 20, 5);
+            assert_eq!(none.len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_render_page_size() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+
+        env.as_contract(&contract_id, || {
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
+            let path = String::from_str(&env, "/?page_size=3");
+            let result = ChunkedExampleContract::render(env.clone(), Some(path), None);
+
+            let mut buf = [0u8; 2048];
+            let len = result.len() as usize;
+            for i in 0..len.min(2048) {
+                if let Some(b) = result.get(i as u32) {
+                    buf[i] = b;
+                }
+            }
+            let s = core::str::from_utf8(&buf[..len.min(2048)]).unwrap_or("");
+
+            // Only 3 comments precede the continuation marker, which resumes at 3
+            let comments = s.find("## Comments").unwrap();
+            let marker = s.find("{{continue collection=\"comments\"").unwrap();
+            assert_eq!(s[comments..marker].matches("> **").count(), 3);
+            assert!(s[marker..].contains("from=3"));
+        });
+    }
+
+    #[test]
+    fn test_continuation_marker_meta() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+
+        env.as_contract(&contract_id, || {
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
+            let meta = Chonk::open(&env, symbol_short!("comments")).meta();
+            let result = ChunkedExampleContract::render(env.clone(), None, None);
+
+            let mut buf = [0u8; 2048];
+            let len = result.len() as usize;
+            for i in 0..len.min(2048) {
+                if let Some(b) = result.get(i as u32) {
+                    buf[i] = b;
+                }
+            }
+            let s = core::str::from_utf8(&buf[..len.min(2048)]).unwrap_or("");
+
+            let mut expected = Bytes::from_slice(&env, b"from=5 total=15 size=");
+            expected.append(&u32_to_bytes(&env, meta.total_bytes));
+            expected.extend_from_slice(b"}}");
+            let mut expected_buf = [0u8; 64];
+            let expected_len = expected.len() as usize;
+            expected.copy_into_slice(&mut expected_buf[..expected_len]);
+
+            let marker = s.find("{{continue collection=\"comments\" ").unwrap();
+            assert!(s[marker..].contains(core::str::from_utf8(&expected_buf[..expected_len]).unwrap()));
+            assert!(meta.total_bytes > 0);
+        });
+    }
+
+    #[test]
+    fn test_pinned_and_comments_markers() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+
+        env.as_contract(&contract_id, || {
+            ChunkedExampleContract::init(env.clone(), Address::generate(&env));
+            assert_eq!(
+                count_all(&env, &[symbol_short!("pinned"), symbol_short!("comments")]),
+                18
+            );
+
+            let result = ChunkedExampleContract::render(env.clone(), None, None);
+
+            let mut buf = [0u8; 2048];
+            let len = result.len() as usize;
+            for i in 0..len.min(2048) {
+                if let Some(b) = result.get(i as u32) {
+                    buf[i] = b;
+                }
+            }
+            let s = core::str::from_utf8(&buf[..len.min(2048)]).unwrap_or("");
+
+            assert!(s.contains("18 posts, 3 pinned"));
+            let pinned = s.find("{{continue collection=\"pinned\" from=2 total=3").unwrap();
+            let comments = s.find("{{continue collection=\"comments\" from=5 total=15").unwrap();
+            assert!(pinned < s.find("## Comments").unwrap());
+            assert!(pinned < comments);
+            assert_eq!(s.matches("{{continue").count(), 2);
+        });
+    }
+
+    #[test]
+    fn test_moderate_comments() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(ChunkedExampleContract, ());
+        let client = ChunkedExampleContractClient::new(&env, &contract_id);
+        let moderator = Address::generate(&env);
+        client.init(&moderator);
+
+        let comments = symbol_short!("comments");
+        let third = client.get_chunk(&comments, &3);
+        client.edit_comment(&2, &String::from_str(&env, "Removed off-topic link."));
+        assert_eq!(env.auths()[0].0, moderator);
+        assert_eq!(
+            client.get_chunk(&comments, &2),
+            Some(Bytes::from_slice(
+                &env,
+                b"> Removed off-topic link. *(edited by moderator)*\n\n"
+            ))
+        );
+        assert_eq!(client.get_chunk(&comments, &3), third);
+
+        let second = client.get_chunk(&comments, &1);
+        client.remove_comment(&0);
+        assert_eq!(client.get_chunk(&comments, &0), second);
+        assert_eq!(
+            client.get_chunk(&comments, &1),
+            Some(Bytes::from_slice(
+                &env,
+                b"> Removed off-topic link. *(edited by moderator)*\n\n"
+            ))
+        );
+        assert_eq!(client.get_chunk(&comments, &14), None);
+        assert_eq!(client.get_chunk_meta(&comments).unwrap().count, 14);
+    }
+
+    #[test]
+    fn test_remove_links_for_moderator() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+        let client = ChunkedExampleContractClient::new(&env, &contract_id);
+        let moderator = Address::generate(&env);
+        client.init(&moderator);
+
+        let render = |viewer: Option<Address>| {
+            let result = client.render(&Some(String::from_str(&env, "/newest")), &viewer);
+            let mut buf = [0u8; 4096];
+            let len = result.len() as usize;
+            result.copy_into_slice(&mut buf[..len]);
+            (buf, len)
+        };
+
+        let (buf, len) = render(Some(moderator.clone()));
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        // Newest-first links point at the stored index
+        assert!(s.contains("(#14)\n\n[Remove](tx:remove_comment {\"id\":14})"));
+        assert_eq!(s.matches("](tx:remove_comment").count(), 5);
+
+        let (buf, len) = render(Some(Address::generate(&env)));
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(!s.contains("remove_comment"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_requires_moderator() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+        let client = ChunkedExampleContractClient::new(&env, &contract_id);
+        client.init(&Address::generate(&env));
+
+        // No moderator signature
+        client.remove_comment(&0);
+    }
+
+    #[test]
+    fn test_reversed_chunk_meta() {
+        let env = Env::default();
+        let contract_id = env.register(ChunkedExampleContract, ());
+        let client = ChunkedExampleContractClient::new(&env, &contract_id);
+        client.init(&Address::generate(&env));
+
+        let reversed = client.get_chunk_meta(&symbol_short!("rcomments"));
+        assert!(reversed.is_some());
+        assert_eq!(reversed, client.get_chunk_meta(&symbol_short!("comments")));
+    }
 }
@@ -0,0 +1,55 @@
+//! Addresses written for display
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Address, Bytes, Env};
+
+/// The strkey form of an address (`G...` or `C...`)
+pub fn address_to_bytes(env: &Env, address: &Address) -> Bytes {
+    string_to_bytes(env, &address.to_string())
+}
+
+/// A shortened address for display: the first 4 and last 4 strkey
+/// characters joined by `…`, e.g. `GABC…WXYZ`. Anything 8 characters or
+/// shorter is returned whole.
+pub fn address_short(env: &Env, address: &Address) -> Bytes {
+    let full = address_to_bytes(env, address);
+    let len = full.len();
+    if len <= 8 {
+        return full;
+    }
+    let mut out = full.slice(0..4);
+    out.extend_from_slice("…".as_bytes());
+    out.append(&full.slice(len - 4..));
+    out
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn to_str(bytes: &Bytes) -> std::string::String {
+        let mut buf = std::vec![0u8; bytes.len() as usize];
+        bytes.copy_into_slice(&mut buf);
+        std::string::String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_address_short() {
+        let env = Env::default();
+        let address = Address::generate(&env);
+
+        let full = address_to_bytes(&env, &address);
+        assert_eq!(full.len(), 56);
+
+        // 4 + "…" + 4 characters, with the ends taken from the strkey
+        let short = address_short(&env, &address);
+        let output = to_str(&short);
+        assert_eq!(output.chars().count(), 9);
+        assert_eq!(output.chars().nth(4), Some('…'));
+        assert_eq!(short.slice(0..4), full.slice(0..4));
+        assert_eq!(short.slice(short.len() - 4..), full.slice(52..));
+    }
+}
@@ -0,0 +1,153 @@
+//! A size budget for renders that loop over user data, so a long list
+//! ends in a continuation marker instead of exceeding host limits
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+pub trait BudgetMode<'a>: Sized {
+    /// Write the rest of the output as `Budget::item`s, keeping the whole
+    /// output within `max_bytes`. Once an item doesn't fit, a truncation
+    /// notice and `{{continue collection="..." from=N}}` are written instead,
+    /// where N counts the items written. Output that already leaves no room
+    /// for the notice is cut back to make room.
+    fn with_budget(self, env: &Env, max_bytes: u32, collection: &'static str) -> Budget;
+}
+
+impl<'a> BudgetMode<'a> for MarkdownBuilder<'a> {
+    fn with_budget(self, env: &Env, max_bytes: u32, collection: &'static str) -> Budget {
+        let mut budget = Budget {
+            env: env.clone(),
+            out: self.build(),
+            max_bytes,
+            collection,
+            written: 0,
+            truncated: false,
+        };
+
+        let notice = budget.notice(0);
+        if budget.out.len() + notice.len() > max_bytes {
+            let mut end = max_bytes.saturating_sub(notice.len());
+            // Back up to a character boundary rather than split a UTF-8 sequence
+            while end > 0 && budget.out.get_unchecked(end) & 0xC0 == 0x80 {
+                end -= 1;
+            }
+            budget.out = budget.out.slice(0..end);
+            budget.out.append(&notice);
+            budget.truncated = true;
+        }
+        budget
+    }
+}
+
+/// Output being written under a byte budget
+pub struct Budget {
+    env: Env,
+    out: Bytes,
+    max_bytes: u32,
+    collection: &'static str,
+    written: u32,
+    truncated: bool,
+}
+
+impl Budget {
+    /// Append one item, or the truncation notice if it would leave no room
+    /// for the notice. Items after a truncation are skipped.
+    pub fn item<F>(mut self, f: F) -> Self
+    where
+        F: for<'b> FnOnce(MarkdownBuilder<'b>) -> MarkdownBuilder<'b>,
+    {
+        if self.truncated {
+            return self;
+        }
+
+        let item = f(MarkdownBuilder::new(&self.env)).build();
+        let reserve = self.notice(self.written + 1).len();
+        if self.out.len() + item.len() + reserve <= self.max_bytes {
+            self.out.append(&item);
+            self.written += 1;
+        } else {
+            let notice = self.notice(self.written);
+            self.out.append(&notice);
+            self.truncated = true;
+        }
+        self
+    }
+
+    /// Whether an item has been cut off
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// The output, ending in the notice if it was truncated
+    pub fn build(self) -> Bytes {
+        self.out
+    }
+
+    fn notice(&self, from: u32) -> Bytes {
+        let mut out = Bytes::from_slice(&self.env, b"\n*Output truncated.*\n\n{{continue collection=\"");
+        out.extend_from_slice(self.collection.as_bytes());
+        out.extend_from_slice(b"\" from=");
+        out.append(&u32_to_bytes(&self.env, from));
+        out.extend_from_slice(b"}}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn test_with_budget() {
+        let env = Env::default();
+        let mut budget = MarkdownBuilder::new(&env)
+            .h2("Comments")
+            .with_budget(&env, 120, "comments");
+        for i in 0..50 {
+            budget = budget.item(|md| md.text("> comment ").number(i).newline().newline());
+        }
+        assert!(budget.is_truncated());
+
+        let output = budget.build();
+        assert!(output.len() <= 120);
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"## Comments\n\n> comment 0\n\n> comment 1\n\n> comment 2\n\n\
+                  \n*Output truncated.*\n\n{{continue collection=\"comments\" from=3}}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_with_budget_already_over() {
+        let env = Env::default();
+        let budget = MarkdownBuilder::new(&env)
+            .text("é".repeat(60).as_str())
+            .with_budget(&env, 101, "comments")
+            .item(|md| md.text("skipped"));
+        assert!(budget.is_truncated());
+
+        let output = budget.build();
+        // Cut back to 36 bytes, not 37, which would split an `é`
+        assert_eq!(output.len(), 100);
+        let notice = b"\n*Output truncated.*\n\n{{continue collection=\"comments\" from=0}}\n";
+        let mut expected = Bytes::from_slice(&env, "é".repeat(18).as_bytes());
+        expected.extend_from_slice(notice);
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_with_budget_fits() {
+        let env = Env::default();
+        let budget = MarkdownBuilder::new(&env)
+            .with_budget(&env, 1000, "comments")
+            .item(|md| md.text("one"))
+            .item(|md| md.text("two"));
+        assert!(!budget.is_truncated());
+        assert_eq!(budget.build(), Bytes::from_slice(&env, b"onetwo"));
+    }
+}
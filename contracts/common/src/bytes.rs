@@ -0,0 +1,213 @@
+//! Byte-level building blocks for output that doesn't go through one of
+//! the SDK builders
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+/// Appends into a single growing `Bytes`, for output assembled from a
+/// handful of pieces where collecting a `Vec<Bytes>` first would only add
+/// an allocation
+pub struct BytesBuilder {
+    bytes: Bytes,
+}
+
+impl BytesBuilder {
+    pub fn new(env: &Env) -> Self {
+        Self {
+            bytes: Bytes::new(env),
+        }
+    }
+
+    pub fn push_slice(mut self, slice: &[u8]) -> Self {
+        self.bytes.extend_from_slice(slice);
+        self
+    }
+
+    pub fn push_bytes(mut self, bytes: &Bytes) -> Self {
+        self.bytes.append(bytes);
+        self
+    }
+
+    /// Append `n` in decimal
+    pub fn push_u32(mut self, n: u32) -> Self {
+        let mut digits = [0u8; 10];
+        let mut i = digits.len();
+        let mut n = n;
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        self.bytes.extend_from_slice(&digits[i..]);
+        self
+    }
+
+    /// What has been appended so far, without finishing the builder
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Clear the output so the builder can produce another fragment.
+    /// `Bytes` live in the host, so there is no capacity to keep; this
+    /// only saves setting up a new builder.
+    pub fn reset(&mut self) {
+        self.bytes = Bytes::new(self.bytes.env());
+    }
+
+    pub fn build(self) -> Bytes {
+        self.bytes
+    }
+}
+
+/// Concatenate static fragments into one `Bytes`, e.g. a shared prefix,
+/// a value, and a suffix
+pub fn bytes_from_slices(env: &Env, slices: &[&[u8]]) -> Bytes {
+    let mut out = Bytes::new(env);
+    for slice in slices {
+        out.extend_from_slice(slice);
+    }
+    out
+}
+
+/// `bytes` as lowercase hex, two digits per byte
+pub fn bytes_to_hex(env: &Env, bytes: &Bytes) -> Bytes {
+    to_hex(env, bytes, b"0123456789abcdef")
+}
+
+/// `bytes` as uppercase hex, two digits per byte
+pub fn bytes_to_hex_upper(env: &Env, bytes: &Bytes) -> Bytes {
+    to_hex(env, bytes, b"0123456789ABCDEF")
+}
+
+fn to_hex(env: &Env, bytes: &Bytes, digits: &[u8; 16]) -> Bytes {
+    let mut out = Bytes::new(env);
+    for b in bytes.iter() {
+        out.push_back(digits[(b >> 4) as usize]);
+        out.push_back(digits[(b & 0x0f) as usize]);
+    }
+    out
+}
+
+pub trait Hex: Sized {
+    /// Append `bytes` as lowercase hex, e.g. for hashes
+    fn hex(self, bytes: &Bytes) -> Self;
+}
+
+impl<'a> Hex for MarkdownBuilder<'a> {
+    fn hex(self, bytes: &Bytes) -> Self {
+        self.raw(bytes_to_hex(bytes.env(), bytes))
+    }
+}
+
+pub trait Reset<'a>: Sized {
+    /// Start over with an empty builder, for producing several independent
+    /// fragments from one binding. The buffer is private to the SDK, so
+    /// this takes the env to set up the replacement.
+    fn reset(&mut self, env: &'a Env);
+}
+
+impl<'a> Reset<'a> for MarkdownBuilder<'a> {
+    fn reset(&mut self, env: &'a Env) {
+        *self = MarkdownBuilder::new(env);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::Vec;
+
+    /// The `Vec<Bytes>` concatenation `BytesBuilder` replaces
+    fn concat_bytes(env: &Env, parts: &Vec<Bytes>) -> Bytes {
+        let mut result = Bytes::new(env);
+        for part in parts.iter() {
+            result.append(&part);
+        }
+        result
+    }
+
+    #[test]
+    fn test_matches_concat_bytes() {
+        let env = Env::default();
+        let name = Bytes::from_slice(&env, b"Stellar");
+
+        let mut parts = Vec::new(&env);
+        parts.push_back(Bytes::from_slice(&env, b"Hello, "));
+        parts.push_back(name.clone());
+        parts.push_back(Bytes::from_slice(&env, b"! You have "));
+        parts.push_back(Bytes::from_slice(&env, b"42"));
+        parts.push_back(Bytes::from_slice(&env, b" tasks."));
+
+        let built = BytesBuilder::new(&env)
+            .push_slice(b"Hello, ")
+            .push_bytes(&name)
+            .push_slice(b"! You have ")
+            .push_u32(42)
+            .push_slice(b" tasks.")
+            .build();
+        assert_eq!(built, concat_bytes(&env, &parts));
+    }
+
+    #[test]
+    fn test_bytes_from_slices() {
+        let env = Env::default();
+
+        let mut manual = Bytes::from_slice(&env, b"> **Alice**: ");
+        manual.append(&Bytes::from_slice(&env, b"Great post!"));
+        manual.append(&Bytes::from_slice(&env, b"\n\n"));
+
+        let built = bytes_from_slices(&env, &[b"> **Alice**: ", b"Great post!", b"\n\n"]);
+        assert_eq!(built, manual);
+        assert!(bytes_from_slices(&env, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_push_u32() {
+        let env = Env::default();
+        let built = BytesBuilder::new(&env)
+            .push_u32(0)
+            .push_slice(b",")
+            .push_u32(u32::MAX)
+            .build();
+        assert_eq!(built, Bytes::from_slice(&env, b"0,4294967295"));
+    }
+
+    #[test]
+    fn test_reset() {
+        let env = Env::default();
+        let mut builder = BytesBuilder::new(&env).push_slice(b"first");
+        let first = builder.as_bytes().clone();
+        builder.reset();
+        assert!(builder.as_bytes().is_empty());
+
+        let second = builder.push_slice(b"second").build();
+        assert_eq!(first, Bytes::from_slice(&env, b"first"));
+        assert_eq!(second, Bytes::from_slice(&env, b"second"));
+    }
+
+    #[test]
+    fn test_markdown_builder_reset() {
+        let env = Env::default();
+
+        // Content written before the reset never reaches the output
+        let mut md = MarkdownBuilder::new(&env).h2("First").text("draft");
+        md.reset(&env);
+        let output = md.text("fresh").build();
+        assert_eq!(output, Bytes::from_slice(&env, b"fresh"));
+    }
+
+    #[test]
+    fn test_bytes_to_hex() {
+        let env = Env::default();
+        let bytes = Bytes::from_slice(&env, &[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(bytes_to_hex(&env, &bytes), Bytes::from_slice(&env, b"deadbeef"));
+        assert_eq!(bytes_to_hex_upper(&env, &bytes), Bytes::from_slice(&env, b"DEADBEEF"));
+        assert!(bytes_to_hex(&env, &Bytes::new(&env)).is_empty());
+
+        let output = MarkdownBuilder::new(&env).text("0x").hex(&bytes).build();
+        assert_eq!(output, Bytes::from_slice(&env, b"0xdeadbeef"));
+    }
+}
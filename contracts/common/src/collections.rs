@@ -0,0 +1,109 @@
+//! Ordering and transform helpers for Soroban `Map` and `Vec`, which
+//! have no `sort`, `filter`, or `map`
+
+use core::cmp::Ordering;
+use soroban_sdk::{Env, IntoVal, Map, TryFromVal, Val, Vec};
+
+/// A map's keys in `Ord` order, so renders iterate in an order that
+/// doesn't depend on how the host compares the key type
+pub fn sorted_keys<K, V>(map: &Map<K, V>) -> Vec<K>
+where
+    K: Ord + IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    sort_by(map.keys(), |a, b| a.cmp(b))
+}
+
+/// `vec` sorted by `cmp`, keeping equal items in their original order
+pub fn sort_by<T, F>(mut vec: Vec<T>, mut cmp: F) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // Insertion sort: lists in renders are short
+    for i in 1..vec.len() {
+        let item = vec.get_unchecked(i);
+        let mut j = i;
+        while j > 0 && cmp(&vec.get_unchecked(j - 1), &item) == Ordering::Greater {
+            vec.set(j, vec.get_unchecked(j - 1));
+            j -= 1;
+        }
+        vec.set(j, item);
+    }
+    vec
+}
+
+/// The items of `vec` for which `keep` returns true, in order
+pub fn filter<T, F>(vec: &Vec<T>, mut keep: F) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    F: FnMut(&T) -> bool,
+{
+    let mut out = Vec::new(vec.env());
+    for i in 0..vec.len() {
+        let item = vec.get_unchecked(i);
+        if keep(&item) {
+            out.push_back(item);
+        }
+    }
+    out
+}
+
+/// `f` applied to each item of `vec`, in order
+pub fn map<T, U, F>(vec: &Vec<T>, mut f: F) -> Vec<U>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    U: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    F: FnMut(T) -> U,
+{
+    let mut out = Vec::new(vec.env());
+    for i in 0..vec.len() {
+        out.push_back(f(vec.get_unchecked(i)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{symbol_short, vec};
+
+    #[test]
+    fn test_sorted_keys() {
+        let env = Env::default();
+        let mut map: Map<u32, u32> = Map::new(&env);
+        for key in [30u32, 10, 20] {
+            map.set(key, key * 2);
+        }
+        assert_eq!(sorted_keys(&map), vec![&env, 10, 20, 30]);
+
+        let mut map = Map::new(&env);
+        map.set(symbol_short!("pear"), 1u32);
+        map.set(symbol_short!("apple"), 2);
+        map.set(symbol_short!("fig"), 3);
+        assert_eq!(
+            sorted_keys(&map),
+            vec![&env, symbol_short!("apple"), symbol_short!("fig"), symbol_short!("pear")]
+        );
+    }
+
+    #[test]
+    fn test_sort_by() {
+        let env = Env::default();
+        let sorted = sort_by(vec![&env, 3u32, 10, 1, 7], |a, b| b.cmp(a));
+        assert_eq!(sorted, vec![&env, 10, 7, 3, 1]);
+
+        // Stable: equal keys keep their order
+        let pairs = vec![&env, (2u32, 1u32), (1, 2), (2, 3), (1, 4)];
+        let sorted = sort_by(pairs, |a, b| a.0.cmp(&b.0));
+        assert_eq!(sorted, vec![&env, (1, 2), (1, 4), (2, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn test_filter_and_map() {
+        let env = Env::default();
+        let numbers = vec![&env, 1u32, 2, 3, 4, 5, 6];
+        assert_eq!(filter(&numbers, |n| n % 2 == 0), vec![&env, 2, 4, 6]);
+        assert_eq!(map(&numbers, |n| n as i128 * 10), vec![&env, 10i128, 20, 30, 40, 50, 60]);
+    }
+}
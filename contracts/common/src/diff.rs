@@ -0,0 +1,84 @@
+//! Line diffs between two render outputs, for snapshot-style debugging
+
+use soroban_sdk::{Bytes, Env, Vec};
+
+/// A ```` ```diff ```` block comparing `old` and `new` line by line. Lines
+/// shared at the start and end are context; everything between is shown
+/// as removed (`-`) then added (`+`), which is exact for a single edited
+/// region and a readable approximation otherwise.
+pub fn diff_render(env: &Env, old: &Bytes, new: &Bytes) -> Bytes {
+    let old_lines = lines(env, old);
+    let new_lines = lines(env, new);
+
+    let mut prefix = 0;
+    while prefix < old_lines.len()
+        && prefix < new_lines.len()
+        && old_lines.get_unchecked(prefix) == new_lines.get_unchecked(prefix)
+    {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < old_lines.len() - prefix
+        && suffix < new_lines.len() - prefix
+        && old_lines.get_unchecked(old_lines.len() - 1 - suffix)
+            == new_lines.get_unchecked(new_lines.len() - 1 - suffix)
+    {
+        suffix += 1;
+    }
+
+    let mut out = Bytes::from_slice(env, b"```diff\n");
+    let mut push = |mark: u8, line: Bytes| {
+        out.push_back(mark);
+        out.append(&line);
+        out.push_back(b'\n');
+    };
+    for i in 0..prefix {
+        push(b' ', old_lines.get_unchecked(i));
+    }
+    for i in prefix..old_lines.len() - suffix {
+        push(b'-', old_lines.get_unchecked(i));
+    }
+    for i in prefix..new_lines.len() - suffix {
+        push(b'+', new_lines.get_unchecked(i));
+    }
+    for i in old_lines.len() - suffix..old_lines.len() {
+        push(b' ', old_lines.get_unchecked(i));
+    }
+    out.extend_from_slice(b"```\n");
+    out
+}
+
+/// Split at `\n`, dropping the empty piece after a trailing newline
+fn lines(env: &Env, text: &Bytes) -> Vec<Bytes> {
+    let mut out = Vec::new(env);
+    let mut start = 0;
+    for i in 0..text.len() {
+        if text.get_unchecked(i) == b'\n' {
+            out.push_back(text.slice(start..i));
+            start = i + 1;
+        }
+    }
+    if start < text.len() {
+        out.push_back(text.slice(start..));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_render() {
+        let env = Env::default();
+        let old = Bytes::from_slice(&env, b"# Tasks\n- one\n- two\n");
+        let new = Bytes::from_slice(&env, b"# Tasks\n- one\n- three\n");
+        assert_eq!(
+            diff_render(&env, &old, &new),
+            Bytes::from_slice(&env, b"```diff\n # Tasks\n - one\n-- two\n+- three\n```\n")
+        );
+
+        let same = diff_render(&env, &old, &old);
+        assert!(!same.iter().any(|c| c == b'+'));
+    }
+}
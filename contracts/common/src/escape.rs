@@ -0,0 +1,117 @@
+//! Escaping for text that comes from storage or the request path
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+/// Markdown punctuation that untrusted text backslash-escapes
+pub(crate) const MARKDOWN_SPECIAL: &[u8] = b"\\`*_{}[]()<>#+-.!|~&";
+
+/// Escape `<`, `>`, `&` and `"` so text can sit inside HTML elements and
+/// attribute values
+pub fn escape_html(env: &Env, text: &Bytes) -> Bytes {
+    let mut out = Bytes::new(env);
+    for c in text.iter() {
+        match c {
+            b'<' => out.extend_from_slice(b"&lt;"),
+            b'>' => out.extend_from_slice(b"&gt;"),
+            b'&' => out.extend_from_slice(b"&amp;"),
+            b'"' => out.extend_from_slice(b"&quot;"),
+            _ => out.push_back(c),
+        }
+    }
+    out
+}
+
+/// Backslash-escape markdown punctuation so stored or echoed text renders
+/// as text rather than as links or markup
+pub fn escape_markdown(env: &Env, text: &Bytes) -> Bytes {
+    let mut out = Bytes::new(env);
+    for c in text.iter() {
+        if MARKDOWN_SPECIAL.contains(&c) {
+            out.push_back(b'\\');
+        }
+        out.push_back(c);
+    }
+    out
+}
+
+/// Append `text` with `<`, `>`, `&` and `"` escaped as HTML entities,
+/// copying the runs between special characters as-is
+pub(crate) fn html_escaped<'a>(mut md: MarkdownBuilder<'a>, text: &str) -> MarkdownBuilder<'a> {
+    let mut start = 0;
+    for (i, c) in text.bytes().enumerate() {
+        let entity = match c {
+            b'<' => "&lt;",
+            b'>' => "&gt;",
+            b'&' => "&amp;",
+            b'"' => "&quot;",
+            _ => continue,
+        };
+        md = md.raw_str(&text[start..i]).raw_str(entity);
+        start = i + 1;
+    }
+    md.raw_str(&text[start..])
+}
+
+/// Append `text` with markdown punctuation backslash-escaped
+pub(crate) fn markdown_escaped<'a>(mut md: MarkdownBuilder<'a>, text: &str) -> MarkdownBuilder<'a> {
+    let mut start = 0;
+    for (i, c) in text.bytes().enumerate() {
+        if MARKDOWN_SPECIAL.contains(&c) {
+            md = md.raw_str(&text[start..i]).raw_str("\\");
+            start = i;
+        }
+    }
+    md.raw_str(&text[start..])
+}
+
+pub trait Escaped: Sized {
+    /// Append `bytes` from storage or the request with markdown punctuation
+    /// escaped, the `Bytes` counterpart of writing `escape_markdown` output
+    fn raw_escaped(self, bytes: &Bytes) -> Self;
+}
+
+impl<'a> Escaped for MarkdownBuilder<'a> {
+    fn raw_escaped(self, bytes: &Bytes) -> Self {
+        self.raw(escape_markdown(bytes.env(), bytes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        let env = Env::default();
+        let text = Bytes::from_slice(&env, b"<b class=\"x\">Tom & Jerry</b>");
+        assert_eq!(
+            escape_html(&env, &text),
+            Bytes::from_slice(&env, b"&lt;b class=&quot;x&quot;&gt;Tom &amp; Jerry&lt;/b&gt;")
+        );
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        let env = Env::default();
+        let text = Bytes::from_slice(&env, b"[x](y) & *z*");
+        assert_eq!(
+            escape_markdown(&env, &text),
+            Bytes::from_slice(&env, b"\\[x\\]\\(y\\) \\& \\*z\\*")
+        );
+    }
+
+    #[test]
+    fn test_raw_escaped() {
+        let env = Env::default();
+        let comment = Bytes::from_slice(&env, b"see [docs](x) and `code`");
+        let output = MarkdownBuilder::new(&env)
+            .text("> ")
+            .raw_escaped(&comment)
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"> see \\[docs\\]\\(x\\) and \\`code\\`")
+        );
+    }
+}
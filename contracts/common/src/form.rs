@@ -0,0 +1,162 @@
+//! Form controls for markdown forms that `MarkdownBuilder` doesn't emit
+//! itself. The markup matches what the viewer collects on `form:` links;
+//! names, values and labels are HTML-escaped.
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::Bytes;
+
+use crate::escape::{escape_html, html_escaped};
+
+pub trait FormControls: Sized {
+    /// `<select name="..">` with one `<option>` per `(value, label)` pair.
+    /// The first option is the default selection.
+    fn select(self, name: &str, options: &[(&str, &str)]) -> Self;
+
+    /// `<input type="hidden">`, for values such as ids or `_redirect` that
+    /// the form submits but the user doesn't edit
+    fn hidden(self, name: &str, value: &Bytes) -> Self;
+
+    /// `<textarea>` pre-filled with `value`, for edit forms
+    fn textarea_value(self, name: &str, rows: u32, value: &Bytes) -> Self;
+
+    /// A one-field form edited in place: a text input holding
+    /// `current_value` and a Save link submitting it to `action`. The
+    /// `data-form` wrapper keeps the viewer from collecting other inputs
+    /// on the page with it.
+    fn inline_form(self, action: &str, field_name: &str, current_value: &Bytes) -> Self;
+
+    /// `inline_form` with `(name, value)` hidden inputs ahead of the field,
+    /// such as the id of the record being edited. They sit inside the
+    /// wrapper, so they are submitted with it.
+    fn inline_form_with(
+        self,
+        action: &str,
+        hidden: &[(&str, &Bytes)],
+        field_name: &str,
+        current_value: &Bytes,
+    ) -> Self;
+}
+
+impl<'a> FormControls for MarkdownBuilder<'a> {
+    fn select(self, name: &str, options: &[(&str, &str)]) -> Self {
+        let mut md = html_escaped(self.raw_str("<select name=\""), name).raw_str("\">");
+        for (value, label) in options {
+            md = html_escaped(md.raw_str("<option value=\""), value).raw_str("\">");
+            md = html_escaped(md, label).raw_str("</option>");
+        }
+        md.raw_str("</select>\n")
+    }
+
+    fn hidden(self, name: &str, value: &Bytes) -> Self {
+        html_escaped(self.raw_str("<input type=\"hidden\" name=\""), name)
+            .raw_str("\" value=\"")
+            .raw(escape_html(value.env(), value))
+            .raw_str("\" />\n")
+    }
+
+    fn textarea_value(self, name: &str, rows: u32, value: &Bytes) -> Self {
+        html_escaped(self.raw_str("<textarea name=\""), name)
+            .raw_str("\" rows=\"")
+            .number(rows)
+            .raw_str("\">")
+            .raw(escape_html(value.env(), value))
+            .raw_str("</textarea>\n")
+    }
+
+    fn inline_form(self, action: &str, field_name: &str, current_value: &Bytes) -> Self {
+        self.inline_form_with(action, &[], field_name, current_value)
+    }
+
+    fn inline_form_with(
+        self,
+        action: &str,
+        hidden: &[(&str, &Bytes)],
+        field_name: &str,
+        current_value: &Bytes,
+    ) -> Self {
+        let mut md = self.raw_str("<span class=\"inline-form\" data-form=\"inline\">");
+        // Kept on one line: a newline would end the inline HTML
+        for (name, value) in hidden {
+            md = html_escaped(md.raw_str("<input type=\"hidden\" name=\""), name)
+                .raw_str("\" value=\"")
+                .raw(escape_html(value.env(), value))
+                .raw_str("\" />");
+        }
+        html_escaped(md.raw_str("<input type=\"text\" name=\""), field_name)
+        .raw_str("\" value=\"")
+        .raw(escape_html(current_value.env(), current_value))
+        .raw_str("\" /> ")
+        .form_link("Save", action)
+        .raw_str("</span>\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn test_select() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .select("priority", &[("0", "None"), ("1", "Low & \"slow\"")])
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"<select name=\"priority\"><option value=\"0\">None</option><option value=\"1\">Low &amp; &quot;slow&quot;</option></select>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_hidden_and_textarea_value() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .hidden("_redirect", &Bytes::from_slice(&env, b"/tasks?msg=\"hi\""))
+            .textarea_value("description", 2, &Bytes::from_slice(&env, b"</textarea>"))
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"<input type=\"hidden\" name=\"_redirect\" value=\"/tasks?msg=&quot;hi&quot;\" />\n<textarea name=\"description\" rows=\"2\">&lt;/textarea&gt;</textarea>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_inline_form() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .inline_form("set_description", "description", &Bytes::from_slice(&env, b"Buy \"milk\""))
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"<span class=\"inline-form\" data-form=\"inline\"><input type=\"text\" name=\"description\" \
+                  value=\"Buy &quot;milk&quot;\" /> [Save](form:set_description)</span>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_inline_form_with() {
+        let env = Env::default();
+        let id = Bytes::from_slice(&env, b"7");
+        let output = MarkdownBuilder::new(&env)
+            .inline_form_with("edit_task", &[("id", &id)], "description", &Bytes::from_slice(&env, b"Walk"))
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"<span class=\"inline-form\" data-form=\"inline\"><input type=\"hidden\" name=\"id\" value=\"7\" />\
+                  <input type=\"text\" name=\"description\" value=\"Walk\" /> [Save](form:edit_task)</span>\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Output format negotiation for render paths
+
+use soroban_sdk::{Bytes, Env};
+
+/// Output format a render path asks for
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Markdown,
+    Json,
+}
+
+/// Format negotiation for render paths, so routes don't need format branches
+pub struct RenderFormat;
+
+impl RenderFormat {
+    /// Split a route into its format and the clean path. A `/json` prefix or
+    /// a `.json` suffix selects JSON, a `.md` suffix selects markdown, and
+    /// anything else defaults to markdown.
+    pub fn from_path(env: &Env, path: &Bytes) -> (Format, Bytes) {
+        let len = path.len();
+        if *path == Bytes::from_slice(env, b"/json") {
+            return (Format::Json, Bytes::from_slice(env, b"/"));
+        }
+        if len >= 6 && path.slice(0..6) == Bytes::from_slice(env, b"/json/") {
+            return (Format::Json, path.slice(5..));
+        }
+        if len > 5 && path.slice(len - 5..) == Bytes::from_slice(env, b".json") {
+            return (Format::Json, path.slice(0..len - 5));
+        }
+        if len > 3 && path.slice(len - 3..) == Bytes::from_slice(env, b".md") {
+            return (Format::Markdown, path.slice(0..len - 3));
+        }
+        (Format::Markdown, path.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render_format_from_path() {
+        let env = Env::default();
+
+        let (format, path) = RenderFormat::from_path(&env, &Bytes::from_slice(&env, b"/tasks.json"));
+        assert_eq!(format, Format::Json);
+        assert_eq!(path, Bytes::from_slice(&env, b"/tasks"));
+
+        let (format, path) = RenderFormat::from_path(&env, &Bytes::from_slice(&env, b"/json/pending"));
+        assert_eq!(format, Format::Json);
+        assert_eq!(path, Bytes::from_slice(&env, b"/pending"));
+
+        let (format, path) = RenderFormat::from_path(&env, &Bytes::from_slice(&env, b"/tasks"));
+        assert_eq!(format, Format::Markdown);
+        assert_eq!(path, Bytes::from_slice(&env, b"/tasks"));
+
+        let (format, path) = RenderFormat::from_path(&env, &Bytes::from_slice(&env, b"/json"));
+        assert_eq!(format, Format::Json);
+        assert_eq!(path, Bytes::from_slice(&env, b"/"));
+    }
+}
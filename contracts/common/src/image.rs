@@ -0,0 +1,104 @@
+//! Markdown images. `[`, `]` and `\` in alt text are backslash-escaped so
+//! the alt text can't end the image early.
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::Bytes;
+
+use crate::query::url_encode;
+
+pub trait Images: Sized {
+    /// `![alt](url)`
+    fn image(self, alt: &str, url: &str) -> Self;
+
+    /// `[![alt](url)](href)`: an image that links to `href`
+    fn image_linked(self, alt: &str, url: &str, href: &str) -> Self;
+
+    /// An image inlined as a `data:` URL, for small contract-held images
+    /// such as an SVG logo. `data` is URL-encoded, so it can be the raw
+    /// SVG markup.
+    fn image_data(self, alt: &str, mime: &str, data: &Bytes) -> Self;
+}
+
+impl<'a> Images for MarkdownBuilder<'a> {
+    fn image(self, alt: &str, url: &str) -> Self {
+        alt_text(self.raw_str("!["), alt)
+            .raw_str("](")
+            .raw_str(url)
+            .raw_str(")")
+    }
+
+    fn image_linked(self, alt: &str, url: &str, href: &str) -> Self {
+        self.raw_str("[")
+            .image(alt, url)
+            .raw_str("](")
+            .raw_str(href)
+            .raw_str(")")
+    }
+
+    fn image_data(self, alt: &str, mime: &str, data: &Bytes) -> Self {
+        alt_text(self.raw_str("!["), alt)
+            .raw_str("](data:")
+            .raw_str(mime)
+            .raw_str(",")
+            .raw(url_encode(data.env(), data))
+            .raw_str(")")
+    }
+}
+
+/// Append `text` with the characters that end alt text backslash-escaped
+fn alt_text<'a>(mut md: MarkdownBuilder<'a>, text: &str) -> MarkdownBuilder<'a> {
+    let mut start = 0;
+    for (i, c) in text.bytes().enumerate() {
+        if matches!(c, b'[' | b']' | b'\\') {
+            md = md.raw_str(&text[start..i]).raw_str("\\");
+            start = i;
+        }
+    }
+    md.raw_str(&text[start..])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn test_image() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .image("Logo [v2]", "https://example.com/logo.png")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"![Logo \\[v2\\]](https://example.com/logo.png)")
+        );
+    }
+
+    #[test]
+    fn test_image_linked() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .image_linked("Logo", "https://example.com/logo.png", "render:/")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"[![Logo](https://example.com/logo.png)](render:/)")
+        );
+    }
+
+    #[test]
+    fn test_image_data() {
+        let env = Env::default();
+        let svg = Bytes::from_slice(&env, b"<svg width=\"1\"/>");
+        let output = MarkdownBuilder::new(&env)
+            .image_data("Dot", "image/svg+xml", &svg)
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"![Dot](data:image/svg+xml,%3Csvg%20width%3D%221%22%2F%3E)"
+            )
+        );
+    }
+}
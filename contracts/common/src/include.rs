@@ -0,0 +1,172 @@
+//! `{{include}}` tags built from real contract addresses
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Address, Bytes, Env};
+
+/// Builds a `{{include contract=... func="..."}}` tag from a real `Address`,
+/// so the contract ID in the tag is always a valid strkey
+pub struct Include<'a> {
+    contract: Address,
+    func: &'a str,
+    args: Option<&'a str>,
+}
+
+impl<'a> Include<'a> {
+    /// Include a function from another contract
+    pub fn contract(contract: &Address) -> Self {
+        Include {
+            contract: contract.clone(),
+            func: "render",
+            args: None,
+        }
+    }
+
+    /// Include a function from this contract
+    pub fn this(env: &Env) -> Self {
+        Self::contract(&env.current_contract_address())
+    }
+
+    pub fn func(mut self, func: &'a str) -> Self {
+        self.func = func;
+        self
+    }
+
+    /// Pass a JSON object to the included function. Viewers decode it and
+    /// forward it as the target's `path` argument.
+    pub fn args(mut self, json: &'a str) -> Self {
+        self.args = Some(json);
+        self
+    }
+
+    pub fn build(&self) -> Bytes {
+        let env = self.contract.env();
+        let mut out = Bytes::from_slice(env, b"{{include contract=");
+        out.append(&string_to_bytes(env, &self.contract.to_string()));
+        out.extend_from_slice(b" func=\"");
+        out.extend_from_slice(self.func.as_bytes());
+        out.push_back(b'"');
+        if let Some(json) = self.args {
+            // Entity-escape so the JSON survives inside a quoted attribute
+            out.extend_from_slice(b" args=\"");
+            for c in json.bytes() {
+                match c {
+                    b'"' => out.extend_from_slice(b"&quot;"),
+                    b'&' => out.extend_from_slice(b"&amp;"),
+                    _ => out.push_back(c),
+                }
+            }
+            out.push_back(b'"');
+        }
+        out.extend_from_slice(b"}}");
+        out
+    }
+}
+
+/// The string value of `key` in the JSON object an include's `args`
+/// arrive as, e.g. `title` from `{"title":"Tasks"}`. Returns `None` for
+/// ordinary paths and missing or non-string keys. JSON escapes in the value
+/// are left as they are.
+pub fn include_arg(path: &Bytes, key: &str) -> Option<Bytes> {
+    if path.first() != Some(b'{') {
+        return None;
+    }
+    let len = path.len();
+    let key = key.as_bytes();
+    let key_len = key.len() as u32;
+    let mut i = 1;
+    while i + key_len + 2 <= len {
+        let quoted = path.get_unchecked(i) == b'"'
+            && path.get_unchecked(i + key_len + 1) == b'"'
+            && key.iter().enumerate().all(|(j, c)| path.get_unchecked(i + 1 + j as u32) == *c);
+        if !quoted {
+            i += 1;
+            continue;
+        }
+
+        // Skip to the opening quote of the value
+        let mut at = i + key_len + 2;
+        while at < len && matches!(path.get_unchecked(at), b' ' | b':') {
+            at += 1;
+        }
+        if at >= len || path.get_unchecked(at) != b'"' {
+            // A matching value or a non-string value; keep looking
+            i += 1;
+            continue;
+        }
+        let start = at + 1;
+        let mut end = start;
+        while end < len && path.get_unchecked(end) != b'"' {
+            end += if path.get_unchecked(end) == b'\\' { 2 } else { 1 };
+        }
+        return (end < len).then(|| path.slice(start..end));
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::contract;
+
+    const THEME_ID: &str = "CCYEOY2JTOQ2JIMLLERAFNHAVKEKMEJDBOTLN6DIIWBHWEIMUA2T2VY4";
+
+    #[contract]
+    struct Including;
+
+    #[test]
+    fn test_include_builder() {
+        let env = Env::default();
+
+        // Matches the builder's string-based include for the same contract
+        let theme = Address::from_str(&env, THEME_ID);
+        assert_eq!(
+            Include::contract(&theme).func("header").build(),
+            MarkdownBuilder::new(&env).include(THEME_ID, "header").build()
+        );
+
+        let contract_id = env.register(Including, ());
+        env.as_contract(&contract_id, || {
+            let mut expected = Bytes::from_slice(&env, b"{{include contract=");
+            expected.append(&string_to_bytes(&env, &contract_id.to_string()));
+            expected.extend_from_slice(b" func=\"footer\"}}");
+            assert_eq!(Include::this(&env).func("footer").build(), expected);
+        });
+    }
+
+    #[test]
+    fn test_include_builder_args() {
+        let env = Env::default();
+        let theme = Address::from_str(&env, THEME_ID);
+
+        let tag = Include::contract(&theme)
+            .func("header")
+            .args("{\"title\":\"Tasks & More\"}")
+            .build();
+
+        let mut expected = Bytes::from_slice(&env, b"{{include contract=");
+        expected.extend_from_slice(THEME_ID.as_bytes());
+        expected.extend_from_slice(
+            b" func=\"header\" args=\"{&quot;title&quot;:&quot;Tasks &amp; More&quot;}\"}}",
+        );
+        assert_eq!(tag, expected);
+    }
+
+    #[test]
+    fn test_include_arg() {
+        let env = Env::default();
+        let args = Bytes::from_slice(&env, b"{\"id\":7, \"title\": \"Say \\\"hi\\\"\"}");
+        assert_eq!(
+            include_arg(&args, "title"),
+            Some(Bytes::from_slice(&env, b"Say \\\"hi\\\""))
+        );
+
+        // Non-string values, missing keys and plain paths have no arg
+        assert_eq!(include_arg(&args, "id"), None);
+        assert_eq!(include_arg(&args, "name"), None);
+        assert_eq!(include_arg(&Bytes::from_slice(&env, b"/tasks"), "title"), None);
+
+        // A value equal to the key isn't mistaken for it
+        let args = Bytes::from_slice(&env, b"{\"kind\":\"title\",\"title\":\"Tasks\"}");
+        assert_eq!(include_arg(&args, "title"), Some(Bytes::from_slice(&env, b"Tasks")));
+    }
+}
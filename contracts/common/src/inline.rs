@@ -0,0 +1,175 @@
+//! Inline HTML elements that markdown has no syntax for. Text is
+//! HTML-escaped.
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env, Vec};
+
+use crate::escape::{escape_html, escape_markdown, html_escaped};
+
+/// Visual style of a status badge, mapped to the theme's `.badge-*` classes
+#[derive(Clone, Copy)]
+pub enum BadgeKind {
+    Success,
+    Warning,
+    Danger,
+}
+
+impl BadgeKind {
+    pub fn class(&self) -> &'static str {
+        match self {
+            BadgeKind::Success => "badge-success",
+            BadgeKind::Warning => "badge-warning",
+            BadgeKind::Danger => "badge-danger",
+        }
+    }
+}
+
+/// Render a `<span class="badge badge-...">label</span>` status badge,
+/// HTML-escaping the label
+pub fn badge(env: &Env, label: &str, kind: BadgeKind) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"<span class=\"badge ");
+    out.extend_from_slice(kind.class().as_bytes());
+    out.extend_from_slice(b"\">");
+    out.append(&escape_html(env, &Bytes::from_slice(env, label.as_bytes())));
+    out.extend_from_slice(b"</span>");
+    out
+}
+
+pub trait Inline: Sized {
+    /// `<kbd>keys</kbd>`, e.g. `kbd("Ctrl+K")`
+    fn kbd(self, keys: &str) -> Self;
+
+    /// A keyboard hint such as "<kbd>N</kbd> Add task", for apps whose
+    /// viewer binds the shortcut
+    fn shortcut_hint(self, action: &str, keys: &str) -> Self;
+
+    /// An inline legend of `(marker, description)` pairs, e.g. a badge or
+    /// a chart glyph and what it means. Markers are written as-is;
+    /// descriptions are escaped.
+    fn legend(self, items: &Vec<(Bytes, Bytes)>) -> Self;
+
+    /// A row of `(label, selected, path)` filter chips. Each links to
+    /// `path`, which the contract builds with that option's selection
+    /// flipped; selected chips are bold and checked.
+    fn filter_chips(self, options: &Vec<(Bytes, bool, Bytes)>) -> Self;
+}
+
+impl<'a> Inline for MarkdownBuilder<'a> {
+    fn kbd(self, keys: &str) -> Self {
+        html_escaped(self.raw_str("<kbd>"), keys).raw_str("</kbd>")
+    }
+
+    fn shortcut_hint(self, action: &str, keys: &str) -> Self {
+        let md = self.raw_str("<span class=\"shortcut-hint\">").kbd(keys).raw_str(" ");
+        html_escaped(md, action).raw_str("</span>")
+    }
+
+    fn legend(self, items: &Vec<(Bytes, Bytes)>) -> Self {
+        let mut md = self.raw_str("<span class=\"legend\">");
+        for (i, (marker, description)) in items.iter().enumerate() {
+            if i > 0 {
+                md = md.raw_str(" · ");
+            }
+            md = md
+                .raw(marker)
+                .raw_str(" ")
+                .raw(escape_html(items.env(), &description));
+        }
+        md.raw_str("</span>\n\n")
+    }
+
+    fn filter_chips(self, options: &Vec<(Bytes, bool, Bytes)>) -> Self {
+        let mut md = self;
+        for (i, (label, selected, path)) in options.iter().enumerate() {
+            if i > 0 {
+                md = md.raw_str(" ");
+            }
+            md = md.raw_str(if selected { "**[✓ " } else { "[" });
+            md = md
+                .raw(escape_markdown(options.env(), &label))
+                .raw_str("](render:")
+                .raw(path)
+                .raw_str(if selected { ")**" } else { ")" });
+        }
+        md.raw_str("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::vec;
+
+    #[test]
+    fn test_kbd() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).kbd("Ctrl+K").build();
+        assert_eq!(output, Bytes::from_slice(&env, b"<kbd>Ctrl+K</kbd>"));
+
+        let output = MarkdownBuilder::new(&env).kbd("<").build();
+        assert_eq!(output, Bytes::from_slice(&env, b"<kbd>&lt;</kbd>"));
+    }
+
+    #[test]
+    fn test_shortcut_hint() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).shortcut_hint("Add task", "N").build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"<span class=\"shortcut-hint\"><kbd>N</kbd> Add task</span>")
+        );
+    }
+
+    #[test]
+    fn test_legend() {
+        let env = Env::default();
+        let items = vec![
+            &env,
+            (Bytes::from_slice(&env, "█".as_bytes()), Bytes::from_slice(&env, b"completed")),
+            (Bytes::from_slice(&env, "░".as_bytes()), Bytes::from_slice(&env, b"<pending>")),
+        ];
+        let output = MarkdownBuilder::new(&env).legend(&items).build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                "<span class=\"legend\">█ completed · ░ &lt;pending&gt;</span>\n\n".as_bytes()
+            )
+        );
+    }
+
+    #[test]
+    fn test_filter_chips() {
+        let env = Env::default();
+        let chip = |label: &[u8], selected: bool, path: &[u8]| {
+            (Bytes::from_slice(&env, label), selected, Bytes::from_slice(&env, path))
+        };
+        let options = vec![
+            &env,
+            chip(b"work", true, b"/tasks?tags=home"),
+            chip(b"home", false, b"/tasks?tags=work,home"),
+        ];
+        let output = MarkdownBuilder::new(&env).filter_chips(&options).build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                "**[✓ work](render:/tasks?tags=home)** [home](render:/tasks?tags=work,home)\n\n".as_bytes()
+            )
+        );
+    }
+
+    #[test]
+    fn test_badge() {
+        let env = Env::default();
+
+        assert_eq!(
+            badge(&env, "done", BadgeKind::Success),
+            Bytes::from_slice(&env, b"<span class=\"badge badge-success\">done</span>")
+        );
+        assert_eq!(
+            badge(&env, "a<b>&", BadgeKind::Danger),
+            Bytes::from_slice(&env, b"<span class=\"badge badge-danger\">a&lt;b&gt;&amp;</span>")
+        );
+    }
+}
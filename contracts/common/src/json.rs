@@ -0,0 +1,680 @@
+//! `soroban-render-json-v1` components that `JsonDocument` doesn't build.
+//! Each function returns one component object as `Bytes`; `document` wraps
+//! a list of them in the format envelope. `Component` describes a whole
+//! page as data instead.
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+/// A complete `soroban-render-json-v1` document with `components` in order
+pub fn document(env: &Env, title: &str, components: &[Bytes]) -> Bytes {
+    wrap(env, title, components.iter().cloned())
+}
+
+/// A bar chart with one `(label, value, color)` entry per bar. Bars without
+/// a color get the viewer's palette.
+pub fn chart_bar(env: &Env, title: &str, data: &[(&str, i128, Option<&str>)]) -> Bytes {
+    chart(env, "bar", title, data)
+}
+
+/// A single-series line chart through `(label, value)` points, drawn in
+/// `color` or the viewer's default
+pub fn chart_line(env: &Env, title: &str, series: &[(&str, i128)], color: Option<&str>) -> Bytes {
+    line(env, title, series.iter().copied(), color)
+}
+
+/// A form component posting `fields` to the contract method `action`
+pub fn form(env: &Env, action: &str, fields: &[Field], submit_label: &str) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"{\"type\":\"form\"");
+    string_field(env, &mut out, "action", action);
+    out.extend_from_slice(b",\"fields\":[");
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push_back(b',');
+        }
+        field.write(env, &mut out);
+    }
+    out.push_back(b']');
+    string_field(env, &mut out, "submitLabel", submit_label);
+    out.push_back(b'}');
+    out
+}
+
+/// One input of a `form`, made with the constructor for its type
+pub struct Field<'a> {
+    name: &'a str,
+    kind: FieldKind<'a>,
+    placeholder: &'a str,
+    label: &'a str,
+    required: bool,
+}
+
+enum FieldKind<'a> {
+    Text,
+    Textarea { rows: u32 },
+    Number { min: Option<i128>, max: Option<i128>, step: Option<i128> },
+    Checkbox,
+    /// `(value, label)` pairs
+    Select(&'a [(&'a str, &'a str)]),
+}
+
+impl<'a> Field<'a> {
+    fn new(name: &'a str, kind: FieldKind<'a>) -> Self {
+        Self {
+            name,
+            kind,
+            placeholder: "",
+            label: "",
+            required: false,
+        }
+    }
+
+    pub fn text(name: &'a str, placeholder: &'a str) -> Self {
+        Self::new(name, FieldKind::Text).placeholder(placeholder)
+    }
+
+    pub fn textarea(name: &'a str, placeholder: &'a str, rows: u32) -> Self {
+        Self::new(name, FieldKind::Textarea { rows }).placeholder(placeholder)
+    }
+
+    /// A numeric input; each bound and the step are left to the viewer
+    /// when `None`
+    pub fn number(
+        name: &'a str,
+        placeholder: &'a str,
+        min: Option<i128>,
+        max: Option<i128>,
+        step: Option<i128>,
+    ) -> Self {
+        Self::new(name, FieldKind::Number { min, max, step }).placeholder(placeholder)
+    }
+
+    pub fn checkbox(name: &'a str, label: &'a str) -> Self {
+        Self::new(name, FieldKind::Checkbox).label(label)
+    }
+
+    /// A dropdown of `(value, label)` options
+    pub fn select(name: &'a str, options: &'a [(&'a str, &'a str)]) -> Self {
+        Self::new(name, FieldKind::Select(options))
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    fn write(&self, env: &Env, out: &mut Bytes) {
+        out.extend_from_slice(b"{\"name\":\"");
+        out.append(&escape_json_bytes(env, self.name.as_bytes()));
+        out.extend_from_slice(b"\"");
+        let kind = match self.kind {
+            FieldKind::Text => "text",
+            FieldKind::Textarea { .. } => "textarea",
+            FieldKind::Number { .. } => "number",
+            FieldKind::Checkbox => "checkbox",
+            FieldKind::Select(_) => "select",
+        };
+        string_field(env, out, "type", kind);
+        if !self.label.is_empty() {
+            string_field(env, out, "label", self.label);
+        }
+        if !self.placeholder.is_empty() {
+            string_field(env, out, "placeholder", self.placeholder);
+        }
+        if self.required {
+            out.extend_from_slice(b",\"required\":true");
+        }
+        match self.kind {
+            FieldKind::Textarea { rows } => number_field(env, out, "rows", rows.into()),
+            FieldKind::Number { min, max, step } => {
+                for (name, value) in [("min", min), ("max", max), ("step", step)] {
+                    if let Some(value) = value {
+                        number_field(env, out, name, value);
+                    }
+                }
+            }
+            FieldKind::Select(options) => {
+                out.extend_from_slice(b",\"options\":[");
+                for (i, (value, label)) in options.iter().enumerate() {
+                    if i > 0 {
+                        out.push_back(b',');
+                    }
+                    out.extend_from_slice(b"{\"value\":\"");
+                    out.append(&escape_json_bytes(env, value.as_bytes()));
+                    out.push_back(b'"');
+                    string_field(env, out, "label", label);
+                    out.push_back(b'}');
+                }
+                out.push_back(b']');
+            }
+            FieldKind::Text | FieldKind::Checkbox => {}
+        }
+        out.push_back(b'}');
+    }
+}
+
+/// A typed component, for building a page as a list rather than a chain
+/// of builder calls. `serialize_components` writes the same JSON as the
+/// equivalent `JsonDocument` calls.
+pub enum Component<'a> {
+    /// Level and text
+    Heading(u32, &'a str),
+    Text(&'a str),
+    Form {
+        action: &'a str,
+        fields: &'a [Field<'a>],
+        submit_label: &'a str,
+    },
+    /// `(label, path, active)` items
+    Navigation(&'a [(&'a str, &'a str, bool)]),
+    /// `(label, value, color)` entries, as for `chart_bar`
+    Chart {
+        kind: ChartKind,
+        title: &'a str,
+        data: &'a [(&'a str, i128, Option<&'a str>)],
+    },
+    /// `(method, label)` actions, each called with the task's id
+    Task {
+        id: u32,
+        text: &'a str,
+        completed: bool,
+        actions: &'a [(&'a str, &'a str)],
+    },
+    Container {
+        class: &'a str,
+        components: &'a [Component<'a>],
+    },
+    Divider,
+}
+
+pub enum ChartKind {
+    Pie,
+    Bar,
+    /// Entries are the line's points; the first entry's color, if any,
+    /// colors the whole line
+    Line,
+}
+
+impl Component<'_> {
+    fn to_bytes(&self, env: &Env) -> Bytes {
+        match self {
+            Component::Heading(level, text) => {
+                let mut out = Bytes::from_slice(env, b"{\"type\":\"heading\"");
+                number_field(env, &mut out, "level", (*level).into());
+                string_field(env, &mut out, "text", text);
+                out.push_back(b'}');
+                out
+            }
+            Component::Text(content) => {
+                let mut out = Bytes::from_slice(env, b"{\"type\":\"text\"");
+                string_field(env, &mut out, "content", content);
+                out.push_back(b'}');
+                out
+            }
+            Component::Form {
+                action,
+                fields,
+                submit_label,
+            } => form(env, action, fields, submit_label),
+            Component::Navigation(items) => {
+                let mut out = Bytes::from_slice(env, b"{\"type\":\"navigation\",\"items\":[");
+                for (i, (label, path, active)) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_back(b',');
+                    }
+                    out.extend_from_slice(b"{\"label\":\"");
+                    out.append(&escape_json_bytes(env, label.as_bytes()));
+                    out.push_back(b'"');
+                    string_field(env, &mut out, "path", path);
+                    if *active {
+                        out.extend_from_slice(b",\"active\":true");
+                    }
+                    out.push_back(b'}');
+                }
+                out.extend_from_slice(b"]}");
+                out
+            }
+            Component::Chart { kind, title, data } => match kind {
+                ChartKind::Pie => chart(env, "pie", title, data),
+                ChartKind::Bar => chart(env, "bar", title, data),
+                ChartKind::Line => {
+                    let color = data.first().and_then(|(_, _, color)| *color);
+                    line(env, title, data.iter().map(|(label, value, _)| (*label, *value)), color)
+                }
+            },
+            Component::Task {
+                id,
+                text,
+                completed,
+                actions,
+            } => {
+                let mut out = Bytes::from_slice(env, b"{\"type\":\"task\"");
+                number_field(env, &mut out, "id", (*id).into());
+                string_field(env, &mut out, "text", text);
+                out.extend_from_slice(if *completed {
+                    b",\"completed\":true,\"actions\":["
+                } else {
+                    b",\"completed\":false,\"actions\":["
+                });
+                for (i, (method, label)) in actions.iter().enumerate() {
+                    if i > 0 {
+                        out.push_back(b',');
+                    }
+                    out.extend_from_slice(b"{\"type\":\"tx\"");
+                    string_field(env, &mut out, "method", method);
+                    out.extend_from_slice(b",\"args\":{\"id\":");
+                    out.append(&i128_to_bytes(env, (*id).into()));
+                    out.push_back(b'}');
+                    string_field(env, &mut out, "label", label);
+                    out.push_back(b'}');
+                }
+                out.extend_from_slice(b"]}");
+                out
+            }
+            Component::Container { class, components } => {
+                let mut out = Bytes::from_slice(env, b"{\"type\":\"container\"");
+                string_field(env, &mut out, "className", class);
+                out.extend_from_slice(b",\"components\":[");
+                for (i, component) in components.iter().enumerate() {
+                    if i > 0 {
+                        out.push_back(b',');
+                    }
+                    out.append(&component.to_bytes(env));
+                }
+                out.extend_from_slice(b"]}");
+                out
+            }
+            Component::Divider => Bytes::from_slice(env, b"{\"type\":\"divider\"}"),
+        }
+    }
+}
+
+/// A complete `soroban-render-json-v1` document from typed components
+pub fn serialize_components(env: &Env, title: &str, components: &[Component]) -> Bytes {
+    wrap(env, title, components.iter().map(|component| component.to_bytes(env)))
+}
+
+/// JSON counterpart of `Pages::empty_state`: `message` as a text component
+/// in an `empty` container
+pub fn empty_state<'a>(doc: JsonDocument<'a>, message: &str) -> JsonDocument<'a> {
+    doc.container_start("empty").text(message).container_end()
+}
+
+/// Compact JSON from this module reformatted with newlines and two-space
+/// indents, for reading while debugging. Keep compact output on-chain.
+pub fn pretty(env: &Env, json: &Bytes) -> Bytes {
+    let mut out = Bytes::new(env);
+    let mut depth = 0u32;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut i = 0;
+    while i < json.len() {
+        let c = json.get_unchecked(i);
+        i += 1;
+        if in_string {
+            out.push_back(c);
+            if escaped {
+                escaped = false;
+            } else if c == b'\\' {
+                escaped = true;
+            } else if c == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            b'"' => {
+                in_string = true;
+                out.push_back(c);
+            }
+            b'{' | b'[' => {
+                out.push_back(c);
+                let close = if c == b'{' { b'}' } else { b']' };
+                if json.get(i) == Some(close) {
+                    // Keep empty objects and arrays on one line
+                    out.push_back(close);
+                    i += 1;
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            b'}' | b']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push_back(c);
+            }
+            b',' => {
+                out.push_back(c);
+                newline(&mut out, depth);
+            }
+            b':' => out.extend_from_slice(b": "),
+            _ => out.push_back(c),
+        }
+    }
+    out
+}
+
+fn newline(out: &mut Bytes, depth: u32) {
+    out.push_back(b'\n');
+    for _ in 0..depth {
+        out.extend_from_slice(b"  ");
+    }
+}
+
+/// The format envelope around `components`
+fn wrap(env: &Env, title: &str, components: impl Iterator<Item = Bytes>) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"{\"format\":\"soroban-render-json-v1\",\"title\":\"");
+    out.append(&escape_json_bytes(env, title.as_bytes()));
+    out.extend_from_slice(b"\",\"components\":[");
+    for (i, component) in components.enumerate() {
+        if i > 0 {
+            out.push_back(b',');
+        }
+        out.append(&component);
+    }
+    out.extend_from_slice(b"]}");
+    out
+}
+
+/// A pie or bar chart of `(label, value, color)` entries
+fn chart(env: &Env, chart_type: &str, title: &str, data: &[(&str, i128, Option<&str>)]) -> Bytes {
+    let mut out = chart_start(env, chart_type, title);
+    for (i, (label, value, color)) in data.iter().enumerate() {
+        if i > 0 {
+            out.push_back(b',');
+        }
+        data_point(env, &mut out, label, *value, *color);
+    }
+    out.extend_from_slice(b"]}");
+    out
+}
+
+/// A line chart through `points`, with one color for the whole line
+fn line<'a>(
+    env: &Env,
+    title: &str,
+    points: impl Iterator<Item = (&'a str, i128)>,
+    color: Option<&str>,
+) -> Bytes {
+    let mut out = chart_start(env, "line", title);
+    for (i, (label, value)) in points.enumerate() {
+        if i > 0 {
+            out.push_back(b',');
+        }
+        data_point(env, &mut out, label, value, None);
+    }
+    out.push_back(b']');
+    if let Some(color) = color {
+        string_field(env, &mut out, "color", color);
+    }
+    out.push_back(b'}');
+    out
+}
+
+/// `{"type":"chart","chartType":"..","title":"..","data":[`
+fn chart_start(env: &Env, chart_type: &str, title: &str) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"{\"type\":\"chart\",\"chartType\":\"");
+    out.extend_from_slice(chart_type.as_bytes());
+    out.push_back(b'"');
+    string_field(env, &mut out, "title", title);
+    out.extend_from_slice(b",\"data\":[");
+    out
+}
+
+/// `{"label":"..","value":N}`, with `"color"` when given
+fn data_point(env: &Env, out: &mut Bytes, label: &str, value: i128, color: Option<&str>) {
+    out.extend_from_slice(b"{\"label\":\"");
+    out.append(&escape_json_bytes(env, label.as_bytes()));
+    out.extend_from_slice(b"\",\"value\":");
+    out.append(&i128_to_bytes(env, value));
+    if let Some(color) = color {
+        string_field(env, out, "color", color);
+    }
+    out.push_back(b'}');
+}
+
+/// `,"name":"value"` with the value escaped
+fn string_field(env: &Env, out: &mut Bytes, name: &str, value: &str) {
+    out.extend_from_slice(b",\"");
+    out.extend_from_slice(name.as_bytes());
+    out.extend_from_slice(b"\":\"");
+    out.append(&escape_json_bytes(env, value.as_bytes()));
+    out.push_back(b'"');
+}
+
+/// `,"name":N`
+fn number_field(env: &Env, out: &mut Bytes, name: &str, value: i128) {
+    out.extend_from_slice(b",\"");
+    out.extend_from_slice(name.as_bytes());
+    out.extend_from_slice(b"\":");
+    out.append(&i128_to_bytes(env, value));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_chart_bar() {
+        let env = Env::default();
+        let chart = chart_bar(
+            &env,
+            "Volume",
+            &[("Jan", 100, Some("#3b82f6")), ("Feb", -5, None)],
+        );
+        assert_eq!(
+            chart,
+            Bytes::from_slice(
+                &env,
+                b"{\"type\":\"chart\",\"chartType\":\"bar\",\"title\":\"Volume\",\"data\":[\
+                  {\"label\":\"Jan\",\"value\":100,\"color\":\"#3b82f6\"},\
+                  {\"label\":\"Feb\",\"value\":-5}]}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_chart_line() {
+        let env = Env::default();
+        let big = 10i128.pow(20);
+        let chart = chart_line(&env, "Supply", &[("Q1", big), ("Q2", big + 1)], None);
+        assert_eq!(
+            chart,
+            Bytes::from_slice(
+                &env,
+                b"{\"type\":\"chart\",\"chartType\":\"line\",\"title\":\"Supply\",\"data\":[\
+                  {\"label\":\"Q1\",\"value\":100000000000000000000},\
+                  {\"label\":\"Q2\",\"value\":100000000000000000001}]}"
+            )
+        );
+
+        let colored = chart_line(&env, "Supply", &[("Q1", 1)], Some("#22c55e"));
+        assert_eq!(
+            colored,
+            Bytes::from_slice(
+                &env,
+                b"{\"type\":\"chart\",\"chartType\":\"line\",\"title\":\"Supply\",\"data\":[\
+                  {\"label\":\"Q1\",\"value\":1}],\"color\":\"#22c55e\"}"
+            )
+        );
+
+        // The component form matches, taking the line color from the first entry
+        let component = Component::Chart {
+            kind: ChartKind::Line,
+            title: "Supply",
+            data: &[("Q1", 1, Some("#22c55e"))],
+        };
+        assert_eq!(component.to_bytes(&env), colored);
+    }
+
+    #[test]
+    fn test_form_fields() {
+        let env = Env::default();
+        let output = form(
+            &env,
+            "add_task",
+            &[
+                Field::text("description", "What needs doing?").required(),
+                Field::select("priority", &[("0", "None"), ("3", "High")]).label("Priority"),
+                Field::number("estimate", "Hours", Some(0), Some(40), None),
+                Field::checkbox("pinned", "Pin to top"),
+                Field::textarea("notes", "", 3),
+            ],
+            "Add Task",
+        );
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"{\"type\":\"form\",\"action\":\"add_task\",\"fields\":[\
+                  {\"name\":\"description\",\"type\":\"text\",\"placeholder\":\"What needs doing?\",\"required\":true},\
+                  {\"name\":\"priority\",\"type\":\"select\",\"label\":\"Priority\",\"options\":[\
+                  {\"value\":\"0\",\"label\":\"None\"},{\"value\":\"3\",\"label\":\"High\"}]},\
+                  {\"name\":\"estimate\",\"type\":\"number\",\"placeholder\":\"Hours\",\"min\":0,\"max\":40},\
+                  {\"name\":\"pinned\",\"type\":\"checkbox\",\"label\":\"Pin to top\"},\
+                  {\"name\":\"notes\",\"type\":\"textarea\",\"rows\":3}\
+                  ],\"submitLabel\":\"Add Task\"}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_empty_state() {
+        let env = Env::default();
+        let output = empty_state(JsonDocument::new(&env, "Tasks"), "No tasks yet.").build();
+        let expected = JsonDocument::new(&env, "Tasks")
+            .container_start("empty")
+            .text("No tasks yet.")
+            .container_end()
+            .build();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_serialize_components() {
+        let env = Env::default();
+
+        // The todo JSON view for a user with one pending task
+        let expected = JsonDocument::new(&env, "Todo List")
+            .heading(1, "Todo List")
+            .form("add_task")
+            .text_field("description", "Enter task description", true)
+            .submit("Add Task")
+            .nav_start()
+            .nav_item("All", "/json", true, true)
+            .nav_item("Pending", "/json/pending", false, false)
+            .nav_end()
+            .pie_chart_start("Task Status")
+            .pie_slice("Completed", 0, "#22c55e", true)
+            .pie_slice("Pending", 1, "#eab308", false)
+            .pie_chart_end()
+            .heading(2, "Your Tasks")
+            .container_start("task-list")
+            .task(1, "Buy \"milk\"", false)
+            .tx_action("complete_task", 1, "Done")
+            .tx_action("delete_task", 1, "Delete")
+            .end()
+            .container_end()
+            .divider()
+            .text("Powered by Soroban Render")
+            .build();
+
+        let components = [
+            Component::Heading(1, "Todo List"),
+            Component::Form {
+                action: "add_task",
+                fields: &[Field::text("description", "Enter task description").required()],
+                submit_label: "Add Task",
+            },
+            Component::Navigation(&[("All", "/json", true), ("Pending", "/json/pending", false)]),
+            Component::Chart {
+                kind: ChartKind::Pie,
+                title: "Task Status",
+                data: &[("Completed", 0, Some("#22c55e")), ("Pending", 1, Some("#eab308"))],
+            },
+            Component::Heading(2, "Your Tasks"),
+            Component::Container {
+                class: "task-list",
+                components: &[Component::Task {
+                    id: 1,
+                    text: "Buy \"milk\"",
+                    completed: false,
+                    actions: &[("complete_task", "Done"), ("delete_task", "Delete")],
+                }],
+            },
+            Component::Divider,
+            Component::Text("Powered by Soroban Render"),
+        ];
+        assert_eq!(serialize_components(&env, "Todo List", &components), expected);
+    }
+
+    #[test]
+    fn test_document() {
+        let env = Env::default();
+        let output = document(&env, "Stats", &[chart_bar(&env, "Empty", &[])]);
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"{\"format\":\"soroban-render-json-v1\",\"title\":\"Stats\",\"components\":[\
+                  {\"type\":\"chart\",\"chartType\":\"bar\",\"title\":\"Empty\",\"data\":[]}]}"
+            )
+        );
+    }
+
+    /// `json` without whitespace outside strings, to compare structure
+    fn compact(env: &Env, json: &Bytes) -> Bytes {
+        let mut out = Bytes::new(env);
+        let mut in_string = false;
+        let mut escaped = false;
+        for c in json.iter() {
+            if in_string {
+                in_string = escaped || c != b'"';
+                escaped = !escaped && c == b'\\';
+            } else if c == b'"' {
+                in_string = true;
+            } else if c == b' ' || c == b'\n' {
+                continue;
+            }
+            out.push_back(c);
+        }
+        out
+    }
+
+    #[test]
+    fn test_pretty() {
+        let env = Env::default();
+        let output = document(&env, "Stats: \"a, b\"", &[chart_bar(&env, "Empty", &[])]);
+        let formatted = pretty(&env, &output);
+        assert_eq!(
+            formatted,
+            Bytes::from_slice(
+                &env,
+                b"{\n  \"format\": \"soroban-render-json-v1\",\n  \"title\": \"Stats: \\\"a, b\\\"\",\n  \
+                  \"components\": [\n    {\n      \"type\": \"chart\",\n      \"chartType\": \"bar\",\n      \
+                  \"title\": \"Empty\",\n      \"data\": []\n    }\n  ]\n}"
+            )
+        );
+        assert_eq!(compact(&env, &formatted), output);
+
+        let components = [
+            Component::Heading(1, "Todo: List"),
+            Component::Divider,
+            Component::Text("a, [b] {c}"),
+        ];
+        let output = serialize_components(&env, "Todo", &components);
+        assert_eq!(compact(&env, &pretty(&env, &output)), output);
+    }
+}
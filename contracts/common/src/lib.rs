@@ -0,0 +1,39 @@
+//! Shared helpers for the Soroban Render example contracts
+//!
+//! Small pieces that several contracts need and that the SDK doesn't
+//! provide yet. Each module depends only on `soroban-sdk` and the
+//! SDK's markdown builder, so any contract can use them; modules for
+//! other SDK builders sit behind the matching feature.
+
+#![no_std]
+
+pub mod address;
+pub mod budget;
+pub mod bytes;
+pub mod collections;
+pub mod diff;
+pub mod escape;
+pub mod form;
+pub mod format;
+pub mod image;
+pub mod include;
+pub mod inline;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod links;
+pub mod markers;
+pub mod meta;
+pub mod nav;
+pub mod numbers;
+pub mod page;
+pub mod query;
+#[cfg(feature = "router")]
+pub mod routes;
+pub mod section;
+pub mod slots;
+#[cfg(feature = "styles")]
+pub mod style;
+pub mod table;
+pub mod time;
+pub mod tx;
+pub mod viewer;
@@ -0,0 +1,102 @@
+//! Reference-style links, so a URL used many times in a page is written
+//! once as `[n]: url` instead of inline at every link
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env, Vec};
+
+pub trait LinkRefMode<'a>: Sized {
+    /// Switch to reference-style links until `build`
+    fn with_link_refs(self, env: &Env) -> LinkRefs<'a>;
+}
+
+impl<'a> LinkRefMode<'a> for MarkdownBuilder<'a> {
+    fn with_link_refs(self, env: &Env) -> LinkRefs<'a> {
+        LinkRefs {
+            md: self,
+            urls: Vec::new(env),
+        }
+    }
+}
+
+/// A builder whose `link`s are written as `[text][n]`, with the URL
+/// definitions appended by `build`
+pub struct LinkRefs<'a> {
+    md: MarkdownBuilder<'a>,
+    urls: Vec<Bytes>,
+}
+
+impl<'a> LinkRefs<'a> {
+    /// Append `[text][n]`, reusing `n` when `url` was linked before
+    pub fn link(mut self, text: &str, url: &str) -> Self {
+        let url = Bytes::from_slice(self.urls.env(), url.as_bytes());
+        let index = match self.urls.first_index_of(&url) {
+            Some(i) => i,
+            None => {
+                self.urls.push_back(url);
+                self.urls.len() - 1
+            }
+        };
+        self.md = self
+            .md
+            .raw_str("[")
+            .raw_str(text)
+            .raw_str("][")
+            .number(index + 1)
+            .raw_str("]");
+        self
+    }
+
+    /// Use any other builder method. Links it writes stay inline.
+    pub fn with<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MarkdownBuilder<'a>) -> MarkdownBuilder<'a>,
+    {
+        self.md = f(self.md);
+        self
+    }
+
+    /// Finish the output with one `[n]: url` line per distinct URL
+    pub fn build(self) -> Bytes {
+        let mut md = self.md;
+        // A definition can't interrupt a paragraph, so leave a blank line
+        if !self.urls.is_empty() {
+            md = md.raw_str("\n\n");
+        }
+        for (i, url) in self.urls.iter().enumerate() {
+            md = md
+                .raw_str("[")
+                .number(i as u32 + 1)
+                .raw_str("]: ")
+                .raw(url)
+                .raw_str("\n");
+        }
+        md.build()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_link_refs() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .with_link_refs(&env)
+            .link("Source", "https://github.com/wyhaines/soroban-render")
+            .with(|md| md.text(" and "))
+            .link("Docs", "https://developers.stellar.org")
+            .with(|md| md.text(" and "))
+            .link("the repo", "https://github.com/wyhaines/soroban-render")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"[Source][1] and [Docs][2] and [the repo][1]\n\n\
+                  [1]: https://github.com/wyhaines/soroban-render\n\
+                  [2]: https://developers.stellar.org\n"
+            )
+        );
+    }
+}
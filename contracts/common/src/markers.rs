@@ -0,0 +1,205 @@
+//! `{{...}}` markers for viewer-side features that have no markdown
+//! equivalent. String attributes are quoted and HTML-escaped; numbers are
+//! written bare.
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Vec};
+
+use crate::escape::html_escaped;
+use crate::query::{percent_encoded, url_encode};
+
+pub trait Markers: Sized {
+    /// `{{skeleton lines=N}}`: a placeholder of `lines` lines the viewer
+    /// shows while a progressively loaded chunk is fetched
+    fn skeleton(self, lines: u32) -> Self;
+
+    /// `{{oracle feed="..."}}`: a value the viewer resolves from a known
+    /// off-chain feed. Panics on an empty feed name.
+    fn oracle_value(self, feed: &str) -> Self;
+
+    /// `{{share label="..." text="..." url="..."}}`: a share link the viewer
+    /// opens with `text` and `url` prefilled. Both are URL-encoded so they
+    /// can go straight into a share intent.
+    fn share_button(self, label: &str, text: &str, url: &str) -> Self;
+
+    /// `{{call contract="..." func="..." args="..."}}`: the return value of a
+    /// contract method, inlined as text. Unlike `{{include}}` this targets
+    /// ordinary methods rather than render functions. The viewer only
+    /// simulates the call, so `func` must be read-only: no auth, no
+    /// storage writes, and a result that doesn't depend on the caller.
+    /// `contract` is a contract id, `SELF` or an alias, as for includes;
+    /// `args` is a JSON object, URL-encoded so its braces can't close the
+    /// marker.
+    fn call_inline(self, contract: &str, func: &str, args: &str) -> Self;
+
+    /// `{{chart type="pie" data="Done:5,Pending:2"}}`: the markdown
+    /// counterpart of the JSON pie chart, one `label:value` per slice.
+    /// Labels are URL-encoded so `:` and `,` in them can't split a slice.
+    fn chart_pie(self, data: &Vec<(Bytes, u32)>) -> Self;
+
+    /// `{{spinner label="..."}}`: a placeholder the viewer animates while
+    /// a pending transaction confirms
+    fn spinner(self, label: &str) -> Self;
+
+    /// `{{ring percent=N}}`: a completion ring for a single metric, with
+    /// `percent` clamped to 100
+    fn progress_ring(self, percent: u32) -> Self;
+}
+
+impl<'a> Markers for MarkdownBuilder<'a> {
+    fn skeleton(self, lines: u32) -> Self {
+        self.raw_str("{{skeleton lines=").number(lines).raw_str("}}")
+    }
+
+    fn oracle_value(self, feed: &str) -> Self {
+        assert!(!feed.is_empty(), "oracle feed name is empty");
+        attr(self.raw_str("{{oracle"), "feed", feed).raw_str("}}")
+    }
+
+    fn share_button(self, label: &str, text: &str, url: &str) -> Self {
+        let md = attr(self.raw_str("{{share"), "label", label);
+        let md = url_encoded(md.raw_str(" text=\""), text);
+        url_encoded(md.raw_str("\" url=\""), url).raw_str("\"}}")
+    }
+
+    fn call_inline(self, contract: &str, func: &str, args: &str) -> Self {
+        let md = attr(self.raw_str("{{call"), "contract", contract);
+        let md = attr(md, "func", func).raw_str(" args=\"");
+        url_encoded(md, args).raw_str("\"}}")
+    }
+
+    fn chart_pie(self, data: &Vec<(Bytes, u32)>) -> Self {
+        let mut md = self.raw_str("{{chart type=\"pie\" data=\"");
+        for (i, (label, value)) in data.iter().enumerate() {
+            if i > 0 {
+                md = md.raw_str(",");
+            }
+            md = md
+                .raw(url_encode(data.env(), &label))
+                .raw_str(":")
+                .number(value);
+        }
+        md.raw_str("\"}}")
+    }
+
+    fn spinner(self, label: &str) -> Self {
+        attr(self.raw_str("{{spinner"), "label", label).raw_str("}}")
+    }
+
+    fn progress_ring(self, percent: u32) -> Self {
+        self.raw_str("{{ring percent=")
+            .number(percent.min(100))
+            .raw_str("}}")
+    }
+}
+
+/// Append ` name="value"` with the value escaped
+fn attr<'a>(md: MarkdownBuilder<'a>, name: &str, value: &str) -> MarkdownBuilder<'a> {
+    html_escaped(md.raw_str(" ").raw_str(name).raw_str("=\""), value).raw_str("\"")
+}
+
+/// Append `text` percent-encoded for a URL
+fn url_encoded<'a>(mut md: MarkdownBuilder<'a>, text: &str) -> MarkdownBuilder<'a> {
+    let mut start = 0;
+    for (i, c) in text.bytes().enumerate() {
+        if let Some(escape) = percent_encoded(c) {
+            // `%XX` is always ASCII
+            let escape = core::str::from_utf8(&escape).unwrap_or_default();
+            md = md.raw_str(&text[start..i]).raw_str(escape);
+            start = i + 1;
+        }
+    }
+    md.raw_str(&text[start..])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{vec, Env};
+
+    #[test]
+    fn test_skeleton() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).skeleton(3).build();
+        assert_eq!(output, Bytes::from_slice(&env, b"{{skeleton lines=3}}"));
+    }
+
+    #[test]
+    fn test_oracle_value() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).oracle_value("XLM/USD").build();
+        assert_eq!(output, Bytes::from_slice(&env, b"{{oracle feed=\"XLM/USD\"}}"));
+    }
+
+    #[test]
+    #[should_panic(expected = "oracle feed name is empty")]
+    fn test_oracle_value_empty_feed() {
+        let env = Env::default();
+        MarkdownBuilder::new(&env).oracle_value("");
+    }
+
+    #[test]
+    fn test_share_button() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .share_button("Share", "My list & more", "https://example.com/?id=1")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"{{share label=\"Share\" text=\"My%20list%20%26%20more\" url=\"https%3A%2F%2Fexample.com%2F%3Fid%3D1\"}}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_call_inline() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .call_inline("SELF", "balance", "{\"id\":1}")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"{{call contract=\"SELF\" func=\"balance\" args=\"%7B%22id%22%3A1%7D\"}}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_chart_pie() {
+        let env = Env::default();
+        let data = vec![
+            &env,
+            (Bytes::from_slice(&env, b"Done"), 5),
+            (Bytes::from_slice(&env, b"Not started"), 2),
+        ];
+        let output = MarkdownBuilder::new(&env).chart_pie(&data).build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"{{chart type=\"pie\" data=\"Done:5,Not%20started:2\"}}")
+        );
+    }
+
+    #[test]
+    fn test_spinner() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).spinner("Saving \"notes\"").build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"{{spinner label=\"Saving &quot;notes&quot;\"}}")
+        );
+    }
+
+    #[test]
+    fn test_progress_ring() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).progress_ring(80).build();
+        assert_eq!(output, Bytes::from_slice(&env, b"{{ring percent=80}}"));
+
+        let output = MarkdownBuilder::new(&env).progress_ring(250).build();
+        assert_eq!(output, Bytes::from_slice(&env, b"{{ring percent=100}}"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Render metadata that the SDK's macros don't declare yet
+
+use soroban_sdk::{Bytes, Env};
+
+/// JSON for a contract's `render_manifest` function, so a viewer can see
+/// what a contract renders without probing it, e.g.
+/// `{"formats":["markdown"],"routes":["/"],"styles":true,"styles_dark":false}`
+pub fn manifest(env: &Env, formats: &[&str], routes: &[&str], styles: bool, styles_dark: bool) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"{\"formats\":");
+    push_string_array(&mut out, formats);
+    out.extend_from_slice(b",\"routes\":");
+    push_string_array(&mut out, routes);
+    out.extend_from_slice(b",\"styles\":");
+    out.extend_from_slice(if styles { b"true" } else { b"false" });
+    out.extend_from_slice(b",\"styles_dark\":");
+    out.extend_from_slice(if styles_dark { b"true" } else { b"false" });
+    out.extend_from_slice(b"}");
+    out
+}
+
+fn push_string_array(out: &mut Bytes, items: &[&str]) {
+    out.extend_from_slice(b"[");
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.extend_from_slice(b",");
+        }
+        out.extend_from_slice(b"\"");
+        for &c in item.as_bytes() {
+            if c == b'"' || c == b'\\' {
+                out.push_back(b'\\');
+            }
+            out.push_back(c);
+        }
+        out.extend_from_slice(b"\"");
+    }
+    out.extend_from_slice(b"]");
+}
+
+/// `render_formats` metadata for a contract that also renders HTML, which
+/// `soroban_render!` and `render_formats!` don't accept. Formats are given
+/// `html` first, then `markdown`, then `json`; any other list is a compile
+/// error, so HTML output is always declared on purpose. Use it with
+/// `render_v1!()` instead of `soroban_render!`, which writes its own
+/// `render_formats`. Also defines `RENDER_FORMATS` with the value written.
+#[macro_export]
+macro_rules! render_formats_html {
+    (html) => {
+        $crate::render_formats_html!(@meta "html");
+    };
+    (html, markdown) => {
+        $crate::render_formats_html!(@meta "html,markdown");
+    };
+    (html, json) => {
+        $crate::render_formats_html!(@meta "html,json");
+    };
+    (html, markdown, json) => {
+        $crate::render_formats_html!(@meta "html,markdown,json");
+    };
+    (@meta $formats:tt) => {
+        ::soroban_sdk::contractmeta!(key = "render_formats", val = $formats);
+
+        /// Formats declared in the `render_formats` metadata
+        pub const RENDER_FORMATS: &str = $formats;
+    };
+}
+
+/// `render_styles` metadata telling viewers which style functions exist:
+/// `render_styles!(light)` for a contract with only `styles`, or
+/// `render_styles!(light, dark)` when it also has `styles_dark`. Also
+/// defines `RENDER_STYLES` with the value written.
+#[macro_export]
+macro_rules! render_styles {
+    (light) => {
+        $crate::render_styles!(@meta "light");
+    };
+    (light, dark) => {
+        $crate::render_styles!(@meta "light,dark");
+    };
+    (@meta $styles:tt) => {
+        ::soroban_sdk::contractmeta!(key = "render_styles", val = $styles);
+
+        /// Styles declared in the `render_styles` metadata
+        pub const RENDER_STYLES: &str = $styles;
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod html_only {
+        crate::render_formats_html!(html);
+    }
+
+    mod html_and_markdown {
+        crate::render_formats_html!(html, markdown);
+    }
+
+    mod light_only {
+        crate::render_styles!(light);
+    }
+
+    mod light_and_dark {
+        crate::render_styles!(light, dark);
+    }
+
+    #[test]
+    fn test_render_formats_html() {
+        assert_eq!(html_only::RENDER_FORMATS, "html");
+        assert_eq!(html_and_markdown::RENDER_FORMATS, "html,markdown");
+    }
+
+    #[test]
+    fn test_render_styles() {
+        assert_eq!(light_only::RENDER_STYLES, "light");
+        assert_eq!(light_and_dark::RENDER_STYLES, "light,dark");
+        assert_ne!(light_only::RENDER_STYLES, light_and_dark::RENDER_STYLES);
+    }
+
+    #[test]
+    fn test_manifest() {
+        let env = Env::default();
+
+        let json = manifest(&env, &["markdown", "json"], &["/", "/task/\"x\""], true, false);
+        assert_eq!(
+            json,
+            Bytes::from_slice(
+                &env,
+                br#"{"formats":["markdown","json"],"routes":["/","/task/\"x\""],"styles":true,"styles_dark":false}"#
+            )
+        );
+    }
+}
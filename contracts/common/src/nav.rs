@@ -0,0 +1,121 @@
+//! Navigation links
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+pub trait NavLinks: Sized {
+    /// A render link with a count badge, e.g. `[Tasks (3)](render:/tasks)`.
+    /// A zero badge renders the plain link.
+    fn render_link_badge(self, label: &str, path: &str, badge: u32) -> Self;
+}
+
+impl<'a> NavLinks for MarkdownBuilder<'a> {
+    fn render_link_badge(self, label: &str, path: &str, badge: u32) -> Self {
+        let mut md = self.raw_str("[").raw_str(label);
+        if badge > 0 {
+            md = md.raw_str(" (").number(badge).raw_str(")");
+        }
+        md.raw_str("](render:").raw_str(path).raw_str(")")
+    }
+}
+
+/// One navigation entry, with its route in each output format
+pub struct NavItem<'a> {
+    pub label: &'a str,
+    pub path: &'a str,
+    pub json_path: &'a str,
+    pub active: bool,
+}
+
+/// A navigation bar declared once and rendered as either markdown links or
+/// a JSON `navigation` component, so the two formats can't drift apart
+pub struct NavBuilder<'a> {
+    items: &'a [NavItem<'a>],
+}
+
+impl<'a> NavBuilder<'a> {
+    pub fn new(items: &'a [NavItem<'a>]) -> Self {
+        NavBuilder { items }
+    }
+
+    /// `[All](render:/tasks) | ...`, with the active item in bold
+    pub fn markdown(&self, env: &Env) -> Bytes {
+        let mut md = MarkdownBuilder::new(env);
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                md = md.text(" | ");
+            }
+            if item.active {
+                md = md.text("**").render_link(item.label, item.path).text("**");
+            } else {
+                md = md.render_link(item.label, item.path);
+            }
+        }
+        md.build()
+    }
+
+    /// Append the items to `doc` as a navigation component
+    #[cfg(feature = "json")]
+    pub fn json<'d>(&self, doc: JsonDocument<'d>) -> JsonDocument<'d> {
+        let mut doc = doc.nav_start();
+        for (i, item) in self.items.iter().enumerate() {
+            doc = doc.nav_item(item.label, item.json_path, item.active, i == 0);
+        }
+        doc.nav_end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FILTERS: [NavItem<'static>; 2] = [
+        NavItem {
+            label: "All",
+            path: "/tasks",
+            json_path: "/json",
+            active: false,
+        },
+        NavItem {
+            label: "Pending",
+            path: "/tasks/pending",
+            json_path: "/json/pending",
+            active: true,
+        },
+    ];
+
+    #[test]
+    fn test_render_link_badge() {
+        let env = Env::default();
+
+        // Zero badge is omitted
+        let plain = MarkdownBuilder::new(&env).render_link_badge("Tasks", "/tasks", 0).build();
+        assert_eq!(plain, Bytes::from_slice(&env, b"[Tasks](render:/tasks)"));
+
+        // Nonzero badge is appended to the label
+        let badged = MarkdownBuilder::new(&env).render_link_badge("Tasks", "/tasks", 3).build();
+        assert_eq!(badged, Bytes::from_slice(&env, b"[Tasks (3)](render:/tasks)"));
+    }
+
+    #[test]
+    fn test_nav_builder_markdown() {
+        let env = Env::default();
+        assert_eq!(
+            NavBuilder::new(&FILTERS).markdown(&env),
+            Bytes::from_slice(&env, b"[All](render:/tasks) | **[Pending](render:/tasks/pending)**")
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_nav_builder_json() {
+        let env = Env::default();
+        let expected = JsonDocument::new(&env, "Nav")
+            .nav_start()
+            .nav_item("All", "/json", false, true)
+            .nav_item("Pending", "/json/pending", true, false)
+            .nav_end()
+            .build();
+        assert_eq!(NavBuilder::new(&FILTERS).json(JsonDocument::new(&env, "Nav")).build(), expected);
+    }
+}
@@ -0,0 +1,151 @@
+//! Numbers formatted for display
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+/// Insert `sep` every three digits from the right of a decimal string,
+/// keeping a leading `-` in place
+fn group_digits(env: &Env, digits: &Bytes, sep: u8) -> Bytes {
+    let mut out = Bytes::new(env);
+    let mut start = 0u32;
+    if digits.get(0) == Some(b'-') {
+        out.push_back(b'-');
+        start = 1;
+    }
+    let count = digits.len() - start;
+    for i in 0..count {
+        if i > 0 && (count - i).is_multiple_of(3) {
+            out.push_back(sep);
+        }
+        out.push_back(digits.get(start + i).unwrap());
+    }
+    out
+}
+
+/// Format a `u32` with a thousands separator, e.g. `1,234,567`
+pub fn u32_to_bytes_grouped(env: &Env, n: u32, sep: u8) -> Bytes {
+    group_digits(env, &u32_to_bytes(env, n), sep)
+}
+
+/// Format an `i128` with a thousands separator, e.g. `-1,234,567`
+pub fn i128_to_bytes_grouped(env: &Env, n: i128, sep: u8) -> Bytes {
+    group_digits(env, &i128_to_bytes(env, n), sep)
+}
+
+/// Format `numerator / denominator` as a percentage with `decimals`
+/// fractional digits, e.g. `33.3%`, using integer math only. Rounds to the
+/// nearest last digit; a zero denominator yields `0%`.
+pub fn percent_to_bytes(env: &Env, numerator: u32, denominator: u32, decimals: u32) -> Bytes {
+    if denominator == 0 {
+        return Bytes::from_slice(env, b"0%");
+    }
+
+    // Cap the scale so the u128 math cannot overflow
+    let decimals = decimals.min(18);
+    let scale = 10u128.pow(decimals);
+    let den = denominator as u128;
+    let scaled = (numerator as u128 * 100 * scale * 2 + den) / (den * 2);
+
+    let mut out = u128_to_bytes(env, scaled / scale);
+    if decimals > 0 {
+        out.push_back(b'.');
+        let frac = u128_to_bytes(env, scaled % scale);
+        for _ in frac.len()..decimals {
+            out.push_back(b'0');
+        }
+        out.append(&frac);
+    }
+    out.push_back(b'%');
+    out
+}
+
+/// Cells in a textual progress bar
+const PROGRESS_WIDTH: u32 = 10;
+
+/// Render a textual progress bar like `[███████░░░] 75%`. `current` is
+/// clamped to `total`; a zero total renders an empty bar at 0%.
+pub fn progress_bar(env: &Env, current: u32, total: u32) -> Bytes {
+    let current = current.min(total);
+    let filled = if total == 0 {
+        0
+    } else {
+        (current as u64 * PROGRESS_WIDTH as u64 / total as u64) as u32
+    };
+
+    let mut out = Bytes::from_slice(env, b"[");
+    for i in 0..PROGRESS_WIDTH {
+        if i < filled {
+            out.extend_from_slice("█".as_bytes());
+        } else {
+            out.extend_from_slice("░".as_bytes());
+        }
+    }
+    out.extend_from_slice(b"] ");
+    out.append(&percent_to_bytes(env, current, total, 0));
+    out
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+
+    fn to_str(bytes: &Bytes) -> std::string::String {
+        let mut buf = std::vec![0u8; bytes.len() as usize];
+        bytes.copy_into_slice(&mut buf);
+        std::string::String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_grouped_digits() {
+        let env = Env::default();
+
+        assert_eq!(u32_to_bytes_grouped(&env, 100, b','), Bytes::from_slice(&env, b"100"));
+        assert_eq!(u32_to_bytes_grouped(&env, 1000, b','), Bytes::from_slice(&env, b"1,000"));
+        assert_eq!(
+            u32_to_bytes_grouped(&env, 1234567, b','),
+            Bytes::from_slice(&env, b"1,234,567")
+        );
+        assert_eq!(
+            i128_to_bytes_grouped(&env, -1234567, b','),
+            Bytes::from_slice(&env, b"-1,234,567")
+        );
+        assert_eq!(i128_to_bytes_grouped(&env, -100, b','), Bytes::from_slice(&env, b"-100"));
+        assert_eq!(
+            i128_to_bytes_grouped(&env, 1234567, b'.'),
+            Bytes::from_slice(&env, b"1.234.567")
+        );
+        assert_eq!(
+            i128_to_bytes_grouped(&env, i128::MIN, b','),
+            Bytes::from_slice(&env, b"-170,141,183,460,469,231,731,687,303,715,884,105,728")
+        );
+    }
+
+    #[test]
+    fn test_percent_to_bytes() {
+        let env = Env::default();
+
+        assert_eq!(percent_to_bytes(&env, 1, 3, 1), Bytes::from_slice(&env, b"33.3%"));
+        assert_eq!(percent_to_bytes(&env, 2, 3, 1), Bytes::from_slice(&env, b"66.7%"));
+        assert_eq!(percent_to_bytes(&env, 1, 20, 2), Bytes::from_slice(&env, b"5.00%"));
+        assert_eq!(percent_to_bytes(&env, 3, 4, 0), Bytes::from_slice(&env, b"75%"));
+        assert_eq!(percent_to_bytes(&env, 0, 0, 1), Bytes::from_slice(&env, b"0%"));
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        let env = Env::default();
+
+        let bar = progress_bar(&env, 3, 4);
+        let output = to_str(&bar);
+        assert_eq!(output.matches('█').count(), 7);
+        assert_eq!(output.matches('░').count(), 3);
+        assert!(output.ends_with("] 75%"));
+
+        // Clamped and empty cases
+        assert_eq!(progress_bar(&env, 9, 4), progress_bar(&env, 4, 4));
+        let empty = progress_bar(&env, 0, 0);
+        assert_eq!(empty.slice(empty.len() - 3..), Bytes::from_slice(&env, b" 0%"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Whole pages and page sections that several routes share
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Address, Bytes, Env};
+
+use crate::escape::escape_markdown;
+use crate::include::Include;
+use crate::query::url_decode;
+
+/// Wrap a page body in the standard shell: the `theme` contract's header
+/// include, nav, a rule, the body, then the theme's footer include
+pub fn page(
+    env: &Env,
+    theme: &Address,
+    header_include: &str,
+    nav: Bytes,
+    body: Bytes,
+    footer_include: &str,
+) -> Bytes {
+    MarkdownBuilder::new(env)
+        .raw(Include::contract(theme).func(header_include).build())
+        .raw(nav)
+        .hr()
+        .raw(body)
+        .raw(Include::contract(theme).func(footer_include).build())
+        .build()
+}
+
+pub trait Pages: Sized {
+    /// A dedicated confirmation page for a destructive transaction, with a
+    /// confirm `tx:` link calling `confirm_method` with the JSON
+    /// `confirm_args` and a cancel `render:` link
+    fn confirm_page(
+        self,
+        message: &str,
+        confirm_method: &str,
+        confirm_args: &Bytes,
+        cancel_path: &str,
+    ) -> Self;
+
+    /// A one-off status message, e.g. from a post-submit `?msg=` redirect,
+    /// as a tip alert with a link back to `dismiss_path`. The message is
+    /// URL-decoded and escaped; line breaks are flattened so it stays in
+    /// the alert.
+    fn toast(self, message: &Bytes, dismiss_path: &str) -> Self;
+
+    /// A placeholder for an empty list: italic text in a paragraph with the
+    /// `empty` class. `message` is static text and is inserted as-is.
+    fn empty_state(self, message: &str) -> Self;
+}
+
+impl<'a> Pages for MarkdownBuilder<'a> {
+    fn confirm_page(
+        self,
+        message: &str,
+        confirm_method: &str,
+        confirm_args: &Bytes,
+        cancel_path: &str,
+    ) -> Self {
+        self.h1("Are you sure?")
+            .warning(message)
+            .raw_str("[Confirm](tx:")
+            .raw_str(confirm_method)
+            .raw_str(" ")
+            .raw(confirm_args.clone())
+            .raw_str(")")
+            .text(" | ")
+            .render_link("Cancel", cancel_path)
+            .newline()
+    }
+
+    fn toast(self, message: &Bytes, dismiss_path: &str) -> Self {
+        let env = message.env();
+        let mut text = Bytes::new(env);
+        for c in url_decode(env, message).iter() {
+            text.push_back(if c == b'\n' || c == b'\r' { b' ' } else { c });
+        }
+
+        self.raw_str("> [!TIP]\n> ")
+            .raw(escape_markdown(env, &text))
+            .raw_str(" ")
+            .render_link("Dismiss", dismiss_path)
+            .newline()
+            .newline()
+    }
+
+    fn empty_state(self, message: &str) -> Self {
+        self.raw_str("<p class=\"empty\"><em>")
+            .raw_str(message)
+            .raw_str("</em></p>\n\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_confirm_page() {
+        let env = Env::default();
+        let args = Bytes::from_slice(&env, b"{\"id\":1}");
+        let output = MarkdownBuilder::new(&env)
+            .confirm_page("This cannot be undone.", "delete_task", &args, "/tasks")
+            .build();
+        let expected = MarkdownBuilder::new(&env)
+            .h1("Are you sure?")
+            .warning("This cannot be undone.")
+            .raw_str("[Confirm](tx:delete_task {\"id\":1}) | [Cancel](render:/tasks)\n")
+            .build();
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_toast() {
+        let env = Env::default();
+        let message = Bytes::from_slice(&env, b"Saved+*all*%0Adone");
+        let output = MarkdownBuilder::new(&env).toast(&message, "/tasks").build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"> [!TIP]\n> Saved \\*all\\* done [Dismiss](render:/tasks)\n\n")
+        );
+    }
+
+    #[test]
+    fn test_empty_state() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env).empty_state("No tasks yet.").build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"<p class=\"empty\"><em>No tasks yet.</em></p>\n\n")
+        );
+    }
+}
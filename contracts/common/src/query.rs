@@ -0,0 +1,164 @@
+//! Render path and query-string parsing for paths such as
+//! `/tasks?page=2&sort=id`
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env, String};
+
+/// The render path as bytes, with a missing or empty path mapped to
+/// `default`. `path_to_bytes` always maps it to `/`, which doesn't suit a
+/// contract whose home page is another route.
+pub fn path_or(env: &Env, path: &Option<String>, default: &str) -> Bytes {
+    match path {
+        Some(path) if !path.is_empty() => string_to_bytes(env, path),
+        _ => Bytes::from_slice(env, default.as_bytes()),
+    }
+}
+
+/// Split a render path at `?` into the route and the query string
+pub fn split_query(env: &Env, path: &Bytes) -> (Bytes, Bytes) {
+    for i in 0..path.len() {
+        if path.get(i) == Some(b'?') {
+            return (path.slice(0..i), path.slice(i + 1..));
+        }
+    }
+    (path.clone(), Bytes::new(env))
+}
+
+/// Look up `key` in an `a=1&b=2` query string
+pub fn query_param(query: &Bytes, key: &[u8]) -> Option<Bytes> {
+    let len = query.len();
+    let key_len = key.len() as u32;
+    let mut start = 0u32;
+    while start < len {
+        let mut end = start;
+        while end < len && query.get(end) != Some(b'&') {
+            end += 1;
+        }
+        if end - start > key_len && query.get(start + key_len) == Some(b'=') {
+            let mut matches = true;
+            for (i, k) in key.iter().enumerate() {
+                if query.get(start + i as u32) != Some(*k) {
+                    matches = false;
+                    break;
+                }
+            }
+            if matches {
+                return Some(query.slice(start + key_len + 1..end));
+            }
+        }
+        start = end + 1;
+    }
+    None
+}
+
+/// Decode a URL query value: `+` becomes a space and `%XX` its byte
+pub fn url_decode(env: &Env, value: &Bytes) -> Bytes {
+    fn hex_val(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let mut out = Bytes::new(env);
+    let len = value.len();
+    let mut i = 0u32;
+    while i < len {
+        let c = value.get(i).unwrap();
+        if c == b'+' {
+            out.push_back(b' ');
+        } else if c == b'%' && i + 2 < len {
+            match (
+                hex_val(value.get(i + 1).unwrap()),
+                hex_val(value.get(i + 2).unwrap()),
+            ) {
+                (Some(hi), Some(lo)) => {
+                    out.push_back(hi * 16 + lo);
+                    i += 2;
+                }
+                _ => out.push_back(c),
+            }
+        } else {
+            out.push_back(c);
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Percent-encode everything but unreserved URL characters
+pub fn url_encode(env: &Env, text: &Bytes) -> Bytes {
+    let mut out = Bytes::new(env);
+    for c in text.iter() {
+        match percent_encoded(c) {
+            Some(escape) => out.extend_from_slice(&escape),
+            None => out.push_back(c),
+        }
+    }
+    out
+}
+
+/// The `%XX` form of `c`, or `None` for the unreserved characters that
+/// `url_encode` copies as-is
+pub(crate) fn percent_encoded(c: u8) -> Option<[u8; 3]> {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    if c.is_ascii_alphanumeric() || b"-_.~".contains(&c) {
+        None
+    } else {
+        Some([b'%', HEX[(c >> 4) as usize], HEX[(c & 0x0f) as usize]])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_path_or() {
+        let env = Env::default();
+        let home = Bytes::from_slice(&env, b"/home");
+        assert_eq!(path_or(&env, &None, "/home"), home);
+        assert_eq!(path_or(&env, &Some(String::from_str(&env, "")), "/home"), home);
+        assert_eq!(
+            path_or(&env, &Some(String::from_str(&env, "/about")), "/home"),
+            Bytes::from_slice(&env, b"/about")
+        );
+    }
+
+    #[test]
+    fn test_split_query() {
+        let env = Env::default();
+        let (route, query) = split_query(&env, &Bytes::from_slice(&env, b"/tasks?page=2"));
+        assert_eq!(route, Bytes::from_slice(&env, b"/tasks"));
+        assert_eq!(query, Bytes::from_slice(&env, b"page=2"));
+
+        let (route, query) = split_query(&env, &Bytes::from_slice(&env, b"/tasks"));
+        assert_eq!(route, Bytes::from_slice(&env, b"/tasks"));
+        assert!(query.is_empty());
+    }
+
+    #[test]
+    fn test_query_param() {
+        let env = Env::default();
+        let query = Bytes::from_slice(&env, b"per_page=5&page=2&q=");
+        assert_eq!(query_param(&query, b"page"), Some(Bytes::from_slice(&env, b"2")));
+        assert_eq!(query_param(&query, b"per_page"), Some(Bytes::from_slice(&env, b"5")));
+        assert_eq!(query_param(&query, b"q"), Some(Bytes::new(&env)));
+        assert_eq!(query_param(&query, b"sort"), None);
+    }
+
+    #[test]
+    fn test_url_round_trip() {
+        let env = Env::default();
+        let text = Bytes::from_slice(&env, b"a b&c/d");
+        let encoded = url_encode(&env, &text);
+        assert_eq!(encoded, Bytes::from_slice(&env, b"a%20b%26c%2Fd"));
+        assert_eq!(url_decode(&env, &encoded), text);
+        assert_eq!(
+            url_decode(&env, &Bytes::from_slice(&env, b"a+b%zz")),
+            Bytes::from_slice(&env, b"a b%zz")
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Route tables that expand to a `Router` chain
+
+#[doc(hidden)]
+pub use soroban_render_sdk::prelude::Router;
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::Bytes;
+
+/// The part of the path a route handler works on: what the route's `*`
+/// captured, or the whole path for routes without one
+pub fn subpath(req: &Request) -> Bytes {
+    req.get_wildcard().unwrap_or_else(|| req.path().clone())
+}
+
+/// Dispatch `path` through a table of routes, first match wins, so the
+/// contract only writes the per-route functions. Each handler is called as
+/// `handler(env, &subpath, viewer)` and returns the rendered `Bytes`; the
+/// `_` entry handles everything else.
+///
+/// ```ignore
+/// render_routes!(&env, path, &viewer, {
+///     b"/" => render_home,
+///     b"/docs/*" => render_doc,
+///     _ => render_404,
+/// })
+/// ```
+#[macro_export]
+macro_rules! render_routes {
+    ($env:expr, $path:expr, $viewer:expr, {
+        $first:literal => $first_handler:path,
+        $($pattern:literal => $handler:path,)*
+        _ => $default:path $(,)?
+    }) => {{
+        let env: &::soroban_sdk::Env = $env;
+        let viewer: &::core::option::Option<::soroban_sdk::Address> = $viewer;
+        $crate::routes::Router::from_bytes(env, $path)
+            .handle($first, |req| $first_handler(env, &$crate::routes::subpath(&req), viewer))
+            $(.or_handle($pattern, |req| $handler(env, &$crate::routes::subpath(&req), viewer)))*
+            .or_default(|req| $default(env, &$crate::routes::subpath(&req), viewer))
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String};
+
+    fn render_home(env: &Env, _subpath: &Bytes, _viewer: &Option<Address>) -> Bytes {
+        Bytes::from_slice(env, b"home")
+    }
+
+    fn render_doc(env: &Env, subpath: &Bytes, viewer: &Option<Address>) -> Bytes {
+        let mut out = Bytes::from_slice(env, if viewer.is_some() { b"doc for viewer:" } else { b"doc:" });
+        out.append(subpath);
+        out
+    }
+
+    fn render_404(env: &Env, _subpath: &Bytes, _viewer: &Option<Address>) -> Bytes {
+        Bytes::from_slice(env, b"not found")
+    }
+
+    #[contract]
+    struct RoutedContract;
+
+    #[contractimpl]
+    impl RoutedContract {
+        pub fn render(env: Env, path: Option<String>, viewer: Option<Address>) -> Bytes {
+            let path = soroban_render_sdk::prelude::path_to_bytes(&env, &path);
+            crate::render_routes!(&env, path, &viewer, {
+                b"/" => render_home,
+                b"/docs/*" => render_doc,
+                _ => render_404,
+            })
+        }
+    }
+
+    #[test]
+    fn test_render_routes() {
+        let env = Env::default();
+        let client = RoutedContractClient::new(&env, &env.register(RoutedContract, ()));
+        let render = |path: &str, viewer: &Option<Address>| {
+            client.render(&Some(String::from_str(&env, path)), viewer)
+        };
+
+        assert_eq!(client.render(&None, &None), Bytes::from_slice(&env, b"home"));
+        assert_eq!(render("/docs/intro", &None), Bytes::from_slice(&env, b"doc:/intro"));
+        assert_eq!(
+            render("/docs/intro", &Some(Address::generate(&env))),
+            Bytes::from_slice(&env, b"doc for viewer:/intro")
+        );
+        assert_eq!(render("/missing", &None), Bytes::from_slice(&env, b"not found"));
+    }
+}
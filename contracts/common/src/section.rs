@@ -0,0 +1,83 @@
+//! Trusted and untrusted sections, so a render states once whether a run
+//! of text is the contract's own markdown or content to be shown as-is
+
+use soroban_render_sdk::prelude::*;
+
+use crate::escape::markdown_escaped;
+
+pub trait Sections<'a>: Sized {
+    /// A section whose `text` is the contract's own markdown, written
+    /// unchanged
+    fn trusted<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Section<'a>) -> Section<'a>;
+
+    /// A section whose `text` is user content, with markdown punctuation
+    /// backslash-escaped
+    fn untrusted<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Section<'a>) -> Section<'a>;
+}
+
+impl<'a> Sections<'a> for MarkdownBuilder<'a> {
+    fn trusted<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Section<'a>) -> Section<'a>,
+    {
+        f(Section { md: self, escape: false }).md
+    }
+
+    fn untrusted<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Section<'a>) -> Section<'a>,
+    {
+        f(Section { md: self, escape: true }).md
+    }
+}
+
+/// The builder inside a `trusted` or `untrusted` scope
+pub struct Section<'a> {
+    md: MarkdownBuilder<'a>,
+    escape: bool,
+}
+
+impl<'a> Section<'a> {
+    /// Append `text`, escaped when the section is untrusted
+    pub fn text(mut self, text: &str) -> Self {
+        self.md = if self.escape {
+            markdown_escaped(self.md, text)
+        } else {
+            self.md.text(text)
+        };
+        self
+    }
+
+    /// Use any other builder method inside the section. What it writes is
+    /// not escaped.
+    pub fn with<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MarkdownBuilder<'a>) -> MarkdownBuilder<'a>,
+    {
+        self.md = f(self.md);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{Bytes, Env};
+
+    #[test]
+    fn test_trusted_and_untrusted() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .trusted(|s| s.text("**Comments**").with(|md| md.newline()))
+            .untrusted(|s| s.text("**Comments**"))
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"**Comments**\n\\*\\*Comments\\*\\*")
+        );
+    }
+}
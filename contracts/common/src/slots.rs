@@ -0,0 +1,118 @@
+//! Named slots, for page parts such as a header title that are written
+//! before the body that decides their content
+//!
+//! The shell marks each slot with `slot(name)`; the body fills `Slots`
+//! while it renders; `Slots::resolve` then swaps every marker for its
+//! value.
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env, Map};
+
+const OPEN: &[u8] = b"{{slot name=\"";
+const CLOSE: &[u8] = b"\"}}";
+
+pub trait SlotMarkers: Sized {
+    /// The placeholder `Slots::resolve` replaces with the slot's value
+    fn slot(self, name: &str) -> Self;
+}
+
+impl<'a> SlotMarkers for MarkdownBuilder<'a> {
+    fn slot(self, name: &str) -> Self {
+        self.raw_str("{{slot name=\"").raw_str(name).raw_str("\"}}")
+    }
+}
+
+/// Slot values collected during a render
+pub struct Slots {
+    env: Env,
+    values: Map<Bytes, Bytes>,
+}
+
+impl Slots {
+    pub fn new(env: &Env) -> Self {
+        Self {
+            env: env.clone(),
+            values: Map::new(env),
+        }
+    }
+
+    /// Set `name`, replacing any earlier value
+    pub fn set(&mut self, name: &str, value: Bytes) {
+        self.values.set(Bytes::from_slice(&self.env, name.as_bytes()), value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<Bytes> {
+        self.values.get(Bytes::from_slice(&self.env, name.as_bytes()))
+    }
+
+    /// `output` with every slot marker replaced by its value. Slots that
+    /// were never set resolve to nothing.
+    pub fn resolve(&self, output: &Bytes) -> Bytes {
+        let mut out = Bytes::new(&self.env);
+        let len = output.len();
+        let mut copied = 0;
+        let mut i = 0;
+        while i < len {
+            if !starts_with(output, i, OPEN) {
+                i += 1;
+                continue;
+            }
+            let name_start = i + OPEN.len() as u32;
+            let Some(name_end) = find(output, name_start, CLOSE) else {
+                break;
+            };
+            out.append(&output.slice(copied..i));
+            if let Some(value) = self.values.get(output.slice(name_start..name_end)) {
+                out.append(&value);
+            }
+            i = name_end + CLOSE.len() as u32;
+            copied = i;
+        }
+        out.append(&output.slice(copied..));
+        out
+    }
+}
+
+fn starts_with(bytes: &Bytes, at: u32, prefix: &[u8]) -> bool {
+    at + prefix.len() as u32 <= bytes.len()
+        && prefix
+            .iter()
+            .enumerate()
+            .all(|(j, c)| bytes.get_unchecked(at + j as u32) == *c)
+}
+
+/// Position of the first `needle` at or after `from`
+fn find(bytes: &Bytes, from: u32, needle: &[u8]) -> Option<u32> {
+    (from..bytes.len()).find(|&i| starts_with(bytes, i, needle))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_title_slot() {
+        let env = Env::default();
+        let mut slots = Slots::new(&env);
+
+        let header = MarkdownBuilder::new(&env).raw_str("# ").slot("title").newline().build();
+        let body = MarkdownBuilder::new(&env).text("Task 7 details").build();
+        slots.set("title", Bytes::from_slice(&env, b"Task #7"));
+
+        let mut page = header;
+        page.append(&body);
+        assert_eq!(slots.get("title"), Some(Bytes::from_slice(&env, b"Task #7")));
+        assert_eq!(
+            slots.resolve(&page),
+            Bytes::from_slice(&env, b"# Task #7\nTask 7 details")
+        );
+    }
+
+    #[test]
+    fn test_unset_slot() {
+        let env = Env::default();
+        let slots = Slots::new(&env);
+        let page = MarkdownBuilder::new(&env).text("a").slot("missing").text("b").build();
+        assert_eq!(slots.resolve(&page), Bytes::from_slice(&env, b"ab"));
+    }
+}
@@ -0,0 +1,149 @@
+//! Rule helpers for `StyleBuilder` and compaction of its output
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+pub trait StyleRules: Sized {
+    /// A base rule plus one rule per `(state, props)` pair on the same
+    /// selector, e.g. `a` and `a:hover`
+    fn rule_with_states(self, selector: &str, props: &str, states: &[(&str, &str)]) -> Self;
+
+    /// A parent rule plus one descendant rule per `(child, props)` pair,
+    /// e.g. `pre` and `pre code`
+    fn nested(self, parent: &str, props: &str, children: &[(&str, &str)]) -> Self;
+}
+
+impl<'a> StyleRules for StyleBuilder<'a> {
+    fn rule_with_states(self, selector: &str, props: &str, states: &[(&str, &str)]) -> Self {
+        let mut css = self.rule(selector, props);
+        let mut buf = [0u8; MAX_SELECTOR];
+        for (state, state_props) in states {
+            css = css.rule(joined(&mut buf, &[selector, ":", state]), state_props);
+        }
+        css
+    }
+
+    fn nested(self, parent: &str, props: &str, children: &[(&str, &str)]) -> Self {
+        let mut css = self.rule(parent, props);
+        let mut buf = [0u8; MAX_SELECTOR];
+        for (child, child_props) in children {
+            css = css.rule(joined(&mut buf, &[parent, " ", child]), child_props);
+        }
+        css
+    }
+}
+
+/// Longest selector the helpers above can build
+const MAX_SELECTOR: usize = 128;
+
+/// `parts` concatenated in `buf`, e.g. a selector and its state
+fn joined<'b>(buf: &'b mut [u8; MAX_SELECTOR], parts: &[&str]) -> &'b str {
+    let mut len = 0;
+    for part in parts {
+        let end = len + part.len();
+        assert!(end <= MAX_SELECTOR, "selector too long");
+        buf[len..end].copy_from_slice(part.as_bytes());
+        len = end;
+    }
+    // Whole `str`s were copied, so this is valid UTF-8
+    core::str::from_utf8(&buf[..len]).unwrap()
+}
+
+/// Strip comments and collapse whitespace in built CSS, leaving quoted
+/// strings alone. Selectors and values are unchanged, so the result
+/// applies the same rules in fewer bytes; the pretty output stays the
+/// default for readability.
+pub fn minify_css(env: &Env, css: &Bytes) -> Bytes {
+    // Whitespace next to these is never significant
+    fn is_delimiter(c: u8) -> bool {
+        b"{};:,>".contains(&c)
+    }
+
+    let mut out = Bytes::new(env);
+    let len = css.len();
+    let mut quote = None;
+    let mut pending_space = false;
+    let mut i = 0u32;
+    while i < len {
+        let c = css.get(i).unwrap();
+        if let Some(q) = quote {
+            out.push_back(c);
+            if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+        if c == b'/' && css.get(i + 1) == Some(b'*') {
+            i += 2;
+            while i < len && !(css.get(i) == Some(b'*') && css.get(i + 1) == Some(b'/')) {
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+        if c.is_ascii_whitespace() {
+            pending_space = true;
+            i += 1;
+            continue;
+        }
+        if pending_space && !is_delimiter(c) && out.last().is_some_and(|prev| !is_delimiter(prev)) {
+            out.push_back(b' ');
+        }
+        pending_space = false;
+        if c == b'"' || c == b'\'' {
+            quote = Some(c);
+        }
+        out.push_back(c);
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rule_with_states() {
+        let env = Env::default();
+        let css = StyleBuilder::new(&env)
+            .rule_with_states("a", "color: blue;", &[("hover", "color: navy;"), ("focus", "outline: 1px;")])
+            .build();
+        let expected = StyleBuilder::new(&env)
+            .rule("a", "color: blue;")
+            .rule("a:hover", "color: navy;")
+            .rule("a:focus", "outline: 1px;")
+            .build();
+        assert_eq!(css, expected);
+    }
+
+    #[test]
+    fn test_nested() {
+        let env = Env::default();
+        let css = StyleBuilder::new(&env)
+            .nested("pre", "padding: 1rem;", &[("code", "color: inherit;")])
+            .build();
+        let expected = StyleBuilder::new(&env)
+            .rule("pre", "padding: 1rem;")
+            .rule("pre code", "color: inherit;")
+            .build();
+        assert_eq!(css, expected);
+    }
+
+    #[test]
+    fn test_minify_css() {
+        let env = Env::default();
+        let css = StyleBuilder::new(&env)
+            .comment("Base")
+            .rule("pre code", "font-family: 'Segoe UI',  monospace;")
+            .rule_start("h1, h2")
+            .prop("margin", "0 0 1rem 0")
+            .rule_end()
+            .build();
+        assert_eq!(
+            minify_css(&env, &css),
+            Bytes::from_slice(&env, b"pre code{font-family:'Segoe UI',monospace;}h1,h2{margin:0 0 1rem 0;}")
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Markdown tables built from rows of cells, with `|` in cell text
+//! escaped so it can't split a cell
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::Bytes;
+
+pub trait Tables<'a>: Sized {
+    /// A table with a trailing "Actions" column. `actions` is called with
+    /// the builder and the row index and appends that row's action links
+    /// (e.g. `tx_link`s); it must not write a newline.
+    fn action_table<F>(self, headers: &[&str], rows: &[&[&str]], actions: F) -> Self
+    where
+        F: FnMut(MarkdownBuilder<'a>, usize) -> MarkdownBuilder<'a>;
+
+    /// The header and delimiter rows of an action table, for callers that
+    /// write rows one at a time with [`Tables::action_row`]
+    fn action_table_header(self, headers: &[&str]) -> Self;
+
+    /// One action table row. `cells` are written as given, so escape any
+    /// `|` in them first; `actions` appends the row's action links as for
+    /// [`Tables::action_table`].
+    fn action_row<F>(self, cells: &[Bytes], actions: F) -> Self
+    where
+        F: FnOnce(MarkdownBuilder<'a>) -> MarkdownBuilder<'a>;
+
+    /// A table whose headers link to `base_path?sort=key` to re-sort it.
+    /// `headers` are (label, sort key) pairs, with URL-safe keys; an empty
+    /// key leaves that column plain. `sort` is the current `?sort=` value,
+    /// `key` or `-key` for descending. The active column shows ▲ or ▼ and
+    /// links to the opposite direction. Rows are written as given, so sort
+    /// (and page) them first.
+    fn sortable_table(
+        self,
+        headers: &[(&str, &str)],
+        rows: &[&[&str]],
+        sort: Option<&Bytes>,
+        base_path: &str,
+    ) -> Self;
+
+    /// The header and delimiter rows of an action table with sortable
+    /// headers as for [`Tables::sortable_table`], for rows written with
+    /// [`Tables::action_row`]
+    fn sortable_action_table_header(
+        self,
+        headers: &[(&str, &str)],
+        sort: Option<&Bytes>,
+        base_path: &str,
+    ) -> Self;
+}
+
+impl<'a> Tables<'a> for MarkdownBuilder<'a> {
+    fn action_table<F>(self, headers: &[&str], rows: &[&[&str]], mut actions: F) -> Self
+    where
+        F: FnMut(MarkdownBuilder<'a>, usize) -> MarkdownBuilder<'a>,
+    {
+        let mut md = self.action_table_header(headers);
+        for (i, row) in rows.iter().enumerate() {
+            md = md.raw_str("|");
+            for value in row.iter() {
+                md = cell(md.raw_str(" "), value).raw_str(" |");
+            }
+            md = actions(md.raw_str(" "), i).raw_str(" |\n");
+        }
+        md.raw_str("\n")
+    }
+
+    fn action_table_header(self, headers: &[&str]) -> Self {
+        let mut md = self.raw_str("|");
+        for header in headers {
+            md = cell(md.raw_str(" "), header).raw_str(" |");
+        }
+        md = md.raw_str(" Actions |\n|");
+        for _ in 0..=headers.len() {
+            md = md.raw_str("---|");
+        }
+        md.raw_str("\n")
+    }
+
+    fn action_row<F>(self, cells: &[Bytes], actions: F) -> Self
+    where
+        F: FnOnce(MarkdownBuilder<'a>) -> MarkdownBuilder<'a>,
+    {
+        let mut md = self.raw_str("|");
+        for value in cells {
+            md = md.raw_str(" ").raw(value.clone()).raw_str(" |");
+        }
+        actions(md.raw_str(" ")).raw_str(" |\n")
+    }
+
+    fn sortable_table(
+        self,
+        headers: &[(&str, &str)],
+        rows: &[&[&str]],
+        sort: Option<&Bytes>,
+        base_path: &str,
+    ) -> Self {
+        let mut md = delimiter(sort_header(self, headers, sort, base_path), headers.len());
+        for row in rows.iter() {
+            md = md.raw_str("|");
+            for value in row.iter() {
+                md = cell(md.raw_str(" "), value).raw_str(" |");
+            }
+            md = md.raw_str("\n");
+        }
+        md.raw_str("\n")
+    }
+
+    fn sortable_action_table_header(
+        self,
+        headers: &[(&str, &str)],
+        sort: Option<&Bytes>,
+        base_path: &str,
+    ) -> Self {
+        let md = sort_header(self, headers, sort, base_path).raw_str(" Actions |");
+        delimiter(md, headers.len() + 1)
+    }
+}
+
+/// The header row of a sortable table, without its newline
+fn sort_header<'a>(
+    md: MarkdownBuilder<'a>,
+    headers: &[(&str, &str)],
+    sort: Option<&Bytes>,
+    base_path: &str,
+) -> MarkdownBuilder<'a> {
+    let mut md = md.raw_str("|");
+    for (label, key) in headers {
+        if key.is_empty() {
+            md = cell(md.raw_str(" "), label).raw_str(" |");
+            continue;
+        }
+        let ascending = sort.is_some_and(|s| *s == Bytes::from_slice(s.env(), key.as_bytes()));
+        let descending = sort.is_some_and(|s| {
+            s.len() == key.len() as u32 + 1
+                && s.get(0) == Some(b'-')
+                && s.slice(1..) == Bytes::from_slice(s.env(), key.as_bytes())
+        });
+
+        md = cell(md.raw_str(" ["), label);
+        if ascending {
+            md = md.raw_str(" ▲");
+        } else if descending {
+            md = md.raw_str(" ▼");
+        }
+        md = md.raw_str("](render:").raw_str(base_path).raw_str("?sort=");
+        if ascending {
+            md = md.raw_str("-");
+        }
+        md = md.raw_str(key).raw_str(") |");
+    }
+    md
+}
+
+/// End a header row and write the delimiter row for `columns` columns
+fn delimiter(md: MarkdownBuilder<'_>, columns: usize) -> MarkdownBuilder<'_> {
+    let mut md = md.raw_str("\n|");
+    for _ in 0..columns {
+        md = md.raw_str("---|");
+    }
+    md.raw_str("\n")
+}
+
+/// Append `text` with `|` escaped
+fn cell<'a>(mut md: MarkdownBuilder<'a>, text: &str) -> MarkdownBuilder<'a> {
+    for (i, part) in text.split('|').enumerate() {
+        if i > 0 {
+            md = md.raw_str("\\|");
+        }
+        md = md.raw_str(part);
+    }
+    md
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use soroban_sdk::{Bytes, Env};
+
+    fn to_str(bytes: &Bytes) -> std::string::String {
+        let mut buf = std::vec![0u8; bytes.len() as usize];
+        bytes.copy_into_slice(&mut buf);
+        std::string::String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_action_table() {
+        let env = Env::default();
+        let ids = [1, 2];
+        let output = MarkdownBuilder::new(&env)
+            .action_table(
+                &["#", "Task"],
+                &[&["1", "Write docs"], &["2", "Ship a|b"]],
+                |md, i| {
+                    md.tx_link_id("Complete", "complete_task", ids[i])
+                        .text(" ")
+                        .tx_link_id("Delete", "delete_task", ids[i])
+                },
+            )
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"| # | Task | Actions |\n|---|---|---|\n\
+                  | 1 | Write docs | [Complete](tx:complete_task {\"id\":1}) [Delete](tx:delete_task {\"id\":1}) |\n\
+                  | 2 | Ship a\\|b | [Complete](tx:complete_task {\"id\":2}) [Delete](tx:delete_task {\"id\":2}) |\n\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_action_rows() {
+        let env = Env::default();
+        let cells = [
+            Bytes::from_slice(&env, b"3"),
+            Bytes::from_slice(&env, b"~~Done~~"),
+        ];
+        let output = MarkdownBuilder::new(&env)
+            .action_table_header(&["#", "Task"])
+            .action_row(&cells, |md| md.tx_link_id("Reopen", "toggle_task", 3))
+            .build();
+        assert_eq!(
+            to_str(&output),
+            "| # | Task | Actions |\n|---|---|---|\n\
+             | 3 | ~~Done~~ | [Reopen](tx:toggle_task {\"id\":3}) |\n"
+        );
+    }
+
+    #[test]
+    fn test_sortable_table() {
+        let env = Env::default();
+        let headers = [("#", "id"), ("Priority", "priority")];
+        let rows: [&[&str]; 2] = [&["2", "High"], &["1", "Low"]];
+
+        let output = MarkdownBuilder::new(&env)
+            .sortable_table(&headers, &rows, Some(&Bytes::from_slice(&env, b"-id")), "/tasks")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                "| [# ▼](render:/tasks?sort=id) | [Priority](render:/tasks?sort=priority) |\n|---|---|\n\
+                 | 2 | High |\n| 1 | Low |\n\n"
+                    .as_bytes()
+            )
+        );
+
+        let output = MarkdownBuilder::new(&env)
+            .sortable_table(&headers, &rows, Some(&Bytes::from_slice(&env, b"priority")), "/tasks")
+            .build();
+        assert!(to_str(&output).contains("[Priority ▲](render:/tasks?sort=-priority)"));
+    }
+
+    #[test]
+    fn test_sortable_action_table_header() {
+        let env = Env::default();
+        let headers = [("#", "id"), ("Task", ""), ("Priority", "priority")];
+        let output = MarkdownBuilder::new(&env)
+            .sortable_action_table_header(&headers, Some(&Bytes::from_slice(&env, b"id")), "/tasks")
+            .action_row(&[Bytes::from_slice(&env, b"1")], |md| md.text("Done"))
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                "| [# ▲](render:/tasks?sort=-id) | Task | [Priority](render:/tasks?sort=priority) | Actions |\n\
+                 |---|---|---|---|\n| 1 | Done |\n"
+                    .as_bytes()
+            )
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Human-readable times relative to the ledger clock
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Env};
+
+/// A span of `secs` in its largest whole unit: "1 minute", "3 hours",
+/// "5 days", or "less than a minute"
+pub fn duration(env: &Env, secs: u64) -> Bytes {
+    if secs < 60 {
+        return Bytes::from_slice(env, b"less than a minute");
+    }
+
+    let (count, unit) = if secs < 3_600 {
+        (secs / 60, "minute")
+    } else if secs < 86_400 {
+        (secs / 3_600, "hour")
+    } else {
+        (secs / 86_400, "day")
+    };
+
+    let mut out = u64_to_bytes(env, count);
+    out.extend_from_slice(b" ");
+    out.extend_from_slice(unit.as_bytes());
+    if count != 1 {
+        out.extend_from_slice(b"s");
+    }
+    out
+}
+
+pub trait Age: Sized {
+    /// How long it has been since `init`, e.g. a contract's stored
+    /// `DataKey::InitializedAt`, as a `duration`
+    fn age_since(self, env: &Env, init: u64) -> Self;
+}
+
+impl<'a> Age for MarkdownBuilder<'a> {
+    fn age_since(self, env: &Env, init: u64) -> Self {
+        let elapsed = env.ledger().timestamp().saturating_sub(init);
+        self.raw(duration(env, elapsed))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Ledger;
+
+    #[test]
+    fn test_age_since() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000_000);
+
+        let output = MarkdownBuilder::new(&env)
+            .text("Live for ")
+            .age_since(&env, 1_000_000 - 3 * 86_400 - 100)
+            .build();
+        assert_eq!(output, Bytes::from_slice(&env, b"Live for 3 days"));
+
+        let output = MarkdownBuilder::new(&env).age_since(&env, 1_000_000).build();
+        assert_eq!(output, Bytes::from_slice(&env, b"less than a minute"));
+    }
+}
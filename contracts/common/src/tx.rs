@@ -0,0 +1,153 @@
+//! `tx:` links with the method name checked before it reaches the viewer
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::{Bytes, Vec};
+
+use crate::escape::markdown_escaped;
+
+/// Whether `name` can be a Soroban `Symbol`, and so a contract method
+/// name: 1 to 32 characters from `a-z`, `A-Z`, `0-9` and `_`
+pub fn is_valid_symbol(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 32
+        && name.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'_')
+}
+
+pub trait TxLinks: Sized {
+    /// `tx_link`, debug-asserting that `method` is a valid symbol
+    fn checked_tx_link(self, text: &str, method: &str, args: &str) -> Self;
+
+    /// `tx_link_id`, debug-asserting that `method` is a valid symbol
+    fn checked_tx_link_id(self, text: &str, method: &str, id: u32) -> Self;
+
+    /// An item's label followed by its `(label, method, args)` actions as
+    /// tx links in an `item-actions` span, which touch viewers can present
+    /// as swipe actions. `label` is markdown-escaped; an empty label writes
+    /// just the span, e.g. for a table's actions cell. Debug-asserts that
+    /// each method is a valid symbol.
+    fn item_actions(self, label: &str, actions: &Vec<(Bytes, Bytes, Bytes)>) -> Self;
+
+    /// A tx link calling `method` with no arguments, for notices the
+    /// viewer acknowledges on-chain (e.g. accepting terms). The contract
+    /// records the acknowledgment for the signing address.
+    fn acknowledge_button(self, label: &str, method: &str) -> Self;
+}
+
+impl<'a> TxLinks for MarkdownBuilder<'a> {
+    fn checked_tx_link(self, text: &str, method: &str, args: &str) -> Self {
+        debug_assert!(is_valid_symbol(method), "tx method is not a valid symbol");
+        self.tx_link(text, method, args)
+    }
+
+    fn checked_tx_link_id(self, text: &str, method: &str, id: u32) -> Self {
+        debug_assert!(is_valid_symbol(method), "tx method is not a valid symbol");
+        self.tx_link_id(text, method, id)
+    }
+
+    fn item_actions(self, label: &str, actions: &Vec<(Bytes, Bytes, Bytes)>) -> Self {
+        let mut md = if label.is_empty() {
+            self
+        } else {
+            markdown_escaped(self, label).raw_str(" ")
+        };
+        md = md.raw_str("<span class=\"item-actions\">");
+        for (i, (text, method, args)) in actions.iter().enumerate() {
+            debug_assert!(
+                (1..=32).contains(&method.len())
+                    && method.iter().all(|c| c.is_ascii_alphanumeric() || c == b'_'),
+                "tx method is not a valid symbol"
+            );
+            if i > 0 {
+                md = md.raw_str(" ");
+            }
+            md = md.raw_str("[").raw(text).raw_str("](tx:").raw(method);
+            if !args.is_empty() {
+                md = md.raw_str(" ").raw(args);
+            }
+            md = md.raw_str(")");
+        }
+        md.raw_str("</span>")
+    }
+
+    fn acknowledge_button(self, label: &str, method: &str) -> Self {
+        self.checked_tx_link(label, method, "")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{vec, Env};
+
+    #[test]
+    fn test_is_valid_symbol() {
+        assert!(is_valid_symbol("complete_task"));
+        assert!(is_valid_symbol(&"a".repeat(32)));
+        assert!(!is_valid_symbol(&"a".repeat(33)));
+        assert!(!is_valid_symbol(""));
+        assert!(!is_valid_symbol("complete task"));
+        assert!(!is_valid_symbol("complete-task"));
+    }
+
+    #[test]
+    fn test_checked_tx_link_id() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .checked_tx_link_id("Done", "complete_task", 1)
+            .build();
+        assert_eq!(output, Bytes::from_slice(&env, b"[Done](tx:complete_task {\"id\":1})"));
+    }
+
+    #[test]
+    #[should_panic(expected = "tx method is not a valid symbol")]
+    fn test_checked_tx_link_long_method() {
+        let env = Env::default();
+        let method = "a_method_name_that_is_forty_chars_long_x";
+        assert_eq!(method.len(), 40);
+        MarkdownBuilder::new(&env).checked_tx_link("Go", method, "");
+    }
+
+    #[test]
+    fn test_item_actions() {
+        let env = Env::default();
+        let action = |text: &[u8], method: &[u8], args: &[u8]| {
+            (
+                Bytes::from_slice(&env, text),
+                Bytes::from_slice(&env, method),
+                Bytes::from_slice(&env, args),
+            )
+        };
+        let actions = vec![
+            &env,
+            action(b"Done", b"complete_task", b"{\"id\":1}"),
+            action(b"Delete", b"delete_task", b"{\"id\":1}"),
+        ];
+        let output = MarkdownBuilder::new(&env).item_actions("Buy milk", &actions).build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"Buy milk <span class=\"item-actions\">[Done](tx:complete_task {\"id\":1}) \
+                  [Delete](tx:delete_task {\"id\":1})</span>"
+            )
+        );
+
+        let output = MarkdownBuilder::new(&env).item_actions("", &actions.slice(..1)).build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(
+                &env,
+                b"<span class=\"item-actions\">[Done](tx:complete_task {\"id\":1})</span>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_acknowledge_button() {
+        let env = Env::default();
+        let output = MarkdownBuilder::new(&env)
+            .acknowledge_button("I accept the terms", "accept_terms")
+            .build();
+        assert_eq!(output, Bytes::from_slice(&env, b"[I accept the terms](tx:accept_terms)"));
+    }
+}
@@ -0,0 +1,73 @@
+//! The wallet viewing a render, and the standard prompt shown when there
+//! isn't one
+
+use soroban_render_sdk::prelude::*;
+use soroban_sdk::Address;
+
+/// The `viewer` argument of `render`, wrapped so wallet-gated routes share
+/// one connect-wallet callout
+pub struct Viewer {
+    address: Option<Address>,
+}
+
+impl Viewer {
+    pub fn new(address: Option<Address>) -> Self {
+        Viewer { address }
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.address.is_some()
+    }
+
+    /// The connected wallet, if any
+    pub fn address(&self) -> Option<&Address> {
+        self.address.as_ref()
+    }
+
+    /// Append the standard "Connect Your Wallet" callout with `message`
+    /// when no wallet is connected; `md` is returned unchanged otherwise.
+    /// Callers then return early when `address` is `None`.
+    pub fn require_connected<'a>(
+        &self,
+        md: MarkdownBuilder<'a>,
+        message: &str,
+    ) -> MarkdownBuilder<'a> {
+        if self.is_connected() {
+            md
+        } else {
+            md.h2("Connect Your Wallet").paragraph(message)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Bytes, Env};
+
+    #[test]
+    fn test_require_connected() {
+        let env = Env::default();
+
+        let disconnected = Viewer::new(None);
+        assert!(!disconnected.is_connected());
+        assert_eq!(disconnected.address(), None);
+        let output = disconnected
+            .require_connected(MarkdownBuilder::new(&env), "Sign in first.")
+            .build();
+        assert_eq!(
+            output,
+            Bytes::from_slice(&env, b"## Connect Your Wallet\n\nSign in first.\n\n")
+        );
+
+        let user = Address::generate(&env);
+        let connected = Viewer::new(Some(user.clone()));
+        assert!(connected.is_connected());
+        assert_eq!(connected.address(), Some(&user));
+        let output = connected
+            .require_connected(MarkdownBuilder::new(&env).h1("Tasks"), "Sign in first.")
+            .build();
+        assert_eq!(output, MarkdownBuilder::new(&env).h1("Tasks").build());
+    }
+}
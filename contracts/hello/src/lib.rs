@@ -4,11 +4,68 @@
 //! This entire file IS the frontend for this dApp.
 
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Symbol};
 use soroban_render_sdk::prelude::*;
+use soroban_render_common::address::address_short;
+use soroban_render_common::meta::manifest;
+use soroban_render_common::query::{query_param, split_query};
+use soroban_render_common::viewer::Viewer;
 
 // Declare render support with styles - viewers check this metadata
 soroban_render!(markdown, styles);
+soroban_render_common::render_styles!(light);
+
+/// Supported `?lang=` codes
+const LANGS: [&str; 2] = ["en", "es"];
+
+/// A phrase keyed by language; the first entry is the fallback
+struct Translations(&'static [(Symbol, &'static str)]);
+
+impl Translations {
+    /// Look up the phrase for a language, falling back to the first entry
+    fn get(&self, lang: &Symbol) -> &'static str {
+        self.0.iter().find(|(key, _)| key == lang).unwrap_or(&self.0[0]).1
+    }
+}
+
+const GREETING_USER: Translations = Translations(&[
+    (symbol_short!("en"), "Hello, Stellar User!"),
+    (symbol_short!("es"), "¡Hola, usuario de Stellar!"),
+]);
+const SIGNED_IN_AS: Translations = Translations(&[
+    (symbol_short!("en"), "Signed in as"),
+    (symbol_short!("es"), "Sesión iniciada como"),
+]);
+const WALLET_CONNECTED: Translations = Translations(&[
+    (symbol_short!("en"), "Your wallet is connected."),
+    (symbol_short!("es"), "Tu billetera está conectada."),
+]);
+const WELCOME: Translations = Translations(&[
+    (symbol_short!("en"), "Welcome to **Soroban Render** - where your smart contract IS your frontend."),
+    (symbol_short!("es"), "Bienvenido a **Soroban Render**, donde tu contrato inteligente ES tu frontend."),
+]);
+const GREETING_WORLD: Translations = Translations(&[
+    (symbol_short!("en"), "Hello, World!"),
+    (symbol_short!("es"), "¡Hola, Mundo!"),
+]);
+const CONNECT_PROMPT: Translations = Translations(&[
+    (symbol_short!("en"), "Connect your wallet to see a personalized greeting."),
+    (symbol_short!("es"), "Conecta tu billetera para ver un saludo personalizado."),
+]);
+const RENDERED_FROM_CONTRACT: Translations = Translations(&[
+    (symbol_short!("en"), "This UI is rendered directly from the smart contract."),
+    (symbol_short!("es"), "Esta interfaz se genera directamente desde el contrato inteligente."),
+]);
+
+/// Read the viewer-supplied `?lang=` code from the render path.
+/// Unsupported or missing codes fall back to English.
+fn lang_from_path(env: &Env, path: &Option<String>) -> Symbol {
+    let (_, query) = split_query(env, &path_to_bytes(env, path));
+    query_param(&query, b"lang")
+        .and_then(|code| LANGS.iter().find(|lang| code == Bytes::from_slice(env, lang.as_bytes())))
+        .map(|lang| Symbol::new(env, lang))
+        .unwrap_or(symbol_short!("en"))
+}
 
 #[contract]
 pub struct HelloContract;
@@ -23,19 +80,36 @@ impl HelloContract {
             .build()
     }
 
+    /// Describe what this contract renders for viewers
+    pub fn render_manifest(env: Env) -> Bytes {
+        manifest(&env, &["markdown"], &["/"], true, false)
+    }
+
     /// Render the contract UI as Markdown.
     /// This single function provides the entire frontend.
-    pub fn render(env: Env, _path: Option<String>, viewer: Option<Address>) -> Bytes {
-        match viewer {
-            Some(_) => MarkdownBuilder::new(&env)
-                .h1("Hello, Stellar User!")
-                .paragraph("Your wallet is connected.")
-                .paragraph("Welcome to **Soroban Render** - where your smart contract IS your frontend.")
+    /// Pass `?lang=es` in the path for a Spanish greeting.
+    pub fn render(env: Env, path: Option<String>, viewer: Option<Address>) -> Bytes {
+        let lang = lang_from_path(&env, &path);
+        let viewer = Viewer::new(viewer);
+
+        match viewer.address() {
+            Some(address) => MarkdownBuilder::new(&env)
+                .h1(GREETING_USER.get(&lang))
+                .text(SIGNED_IN_AS.get(&lang))
+                .text(" `")
+                .raw(address_short(&env, address))
+                .text("`")
+                .newline()
+                .newline()
+                .paragraph(WALLET_CONNECTED.get(&lang))
+                .paragraph(WELCOME.get(&lang))
                 .build(),
-            None => MarkdownBuilder::new(&env)
-                .h1("Hello, World!")
-                .paragraph("Connect your wallet to see a personalized greeting.")
-                .paragraph("This UI is rendered directly from the smart contract.")
+            None => viewer
+                .require_connected(
+                    MarkdownBuilder::new(&env).h1(GREETING_WORLD.get(&lang)),
+                    CONNECT_PROMPT.get(&lang),
+                )
+                .paragraph(RENDERED_FROM_CONTRACT.get(&lang))
                 .build(),
         }
     }
@@ -65,7 +139,7 @@ mod test {
         let output = core::str::from_utf8(&bytes_vec[..len]).unwrap();
 
         assert!(output.contains("Hello, World!"));
-        assert!(output.contains("Connect your wallet"));
+        assert!(output.contains("## Connect Your Wallet\n\nConnect your wallet"));
     }
 
     #[test]
@@ -88,5 +162,50 @@ mod test {
 
         assert!(output.contains("Hello, Stellar User!"));
         assert!(output.contains("wallet is connected"));
+        assert!(output.contains("Signed in as `"));
+    }
+
+    #[test]
+    fn test_render_with_lang() {
+        let env = Env::default();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        let path = String::from_str(&env, "/?lang=es");
+        let result = client.render(&Some(path), &None);
+
+        let mut bytes_vec: [u8; 512] = [0; 512];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                bytes_vec[i] = b;
+            }
+        }
+        let output = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+
+        assert!(output.contains("¡Hola, Mundo!"));
+        assert!(!output.contains("Hello, World!"));
+
+        // The param can appear anywhere in the query; unknown codes fall back to English
+        let mixed = Some(String::from_str(&env, "/?a=1&lang=es"));
+        assert_eq!(lang_from_path(&env, &mixed), symbol_short!("es"));
+        let unknown = Some(String::from_str(&env, "/?lang=xx"));
+        assert_eq!(lang_from_path(&env, &unknown), symbol_short!("en"));
+        assert_eq!(lang_from_path(&env, &None), symbol_short!("en"));
+    }
+
+    #[test]
+    fn test_render_manifest() {
+        let env = Env::default();
+        let contract_id = env.register(HelloContract, ());
+        let client = HelloContractClient::new(&env, &contract_id);
+
+        assert_eq!(
+            client.render_manifest(),
+            Bytes::from_slice(
+                &env,
+                br#"{"formats":["markdown"],"routes":["/"],"styles":true,"styles_dark":false}"#
+            )
+        );
     }
 }
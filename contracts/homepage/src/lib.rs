@@ -4,8 +4,14 @@
 
 #![no_std]
 
+use soroban_render_common::collections::sorted_keys;
+use soroban_render_common::escape::{escape_markdown, Escaped};
+use soroban_render_common::image::Images;
+use soroban_render_common::inline::Inline;
+use soroban_render_common::query::{query_param, split_query, url_decode, url_encode};
+use soroban_render_common::time::Age;
 use soroban_render_sdk::prelude::*;
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env, Map, String, Vec};
 
 soroban_render!(markdown);
 
@@ -17,13 +23,178 @@ pub struct DemoInfo {
     pub description: String,
     pub contract_id: String,
     pub features: String,
+    pub category: String, // Empty for demos registered before categories
+}
+
+/// Demo layout stored before `category` existed
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacyDemoInfo {
+    pub name: String,
+    pub description: String,
+    pub contract_id: String,
+    pub features: String,
 }
 
 #[contracttype]
 pub enum DataKey {
-    Demos,
+    Demos, // Legacy Vec<LegacyDemoInfo>, migrated to DemosV2 on write
     ViewerUrl,
     Network,
+    DemosV2, // Vec<DemoInfo>
+    Admin,   // Address allowed to manage the demo catalog
+    InitializedAt, // Ledger timestamp of `init`
+}
+
+/// Demos shown per page of the homepage
+const DEMOS_PER_PAGE: u32 = 5;
+
+/// Page logo, inlined as a `data:` URL so it needs no hosting
+const LOGO_SVG: &[u8] = b"<svg xmlns='http://www.w3.org/2000/svg' width='48' height='48'>\
+<rect width='48' height='48' rx='10' fill='#7857e1'/>\
+<path d='M14 30l10-14 10 14z' fill='#fff'/></svg>";
+
+/// Shields badge linking to the project source
+const SOURCE_BADGE: &str = "https://img.shields.io/badge/source-GitHub-7857e1";
+const SOURCE_URL: &str = "https://github.com/wyhaines/soroban-render";
+
+/// sha256("Test SDF Network ; September 2015")
+const TESTNET_NETWORK_ID: [u8; 32] = [
+    0xce, 0xe0, 0x30, 0x2d, 0x59, 0x84, 0x4d, 0x32, 0xbd, 0xca, 0x91, 0x5c, 0x82, 0x03, 0xdd, 0x44,
+    0xb3, 0x3f, 0xbb, 0x7e, 0xdc, 0x19, 0x05, 0x1e, 0xa3, 0x7a, 0xbe, 0xdf, 0x28, 0xec, 0xd4, 0x72,
+];
+
+/// sha256("Public Global Stellar Network ; September 2015")
+const MAINNET_NETWORK_ID: [u8; 32] = [
+    0x7a, 0xc3, 0x39, 0x97, 0x54, 0x4e, 0x31, 0x75, 0xd2, 0x66, 0xbd, 0x02, 0x24, 0x39, 0xb2, 0x2c,
+    0xdb, 0x16, 0x50, 0x8c, 0x01, 0x16, 0x3f, 0x26, 0xe5, 0xcb, 0x2a, 0x3e, 0x10, 0x45, 0xa9, 0x79,
+];
+
+/// Name the network the contract is running on, derived from the ledger's network id
+fn network_name(env: &Env) -> &'static str {
+    let id = env.ledger().network_id().to_array();
+    if id == TESTNET_NETWORK_ID {
+        "Testnet"
+    } else if id == MAINNET_NETWORK_ID {
+        "Mainnet"
+    } else {
+        "Unknown"
+    }
+}
+
+/// Build a `[label](render:/?page=N)` link, carrying the category filter
+/// (a `category_param` value, empty for none)
+fn page_link(env: &Env, label: &str, page: u32, categories: &Bytes) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"[");
+    out.extend_from_slice(label.as_bytes());
+    out.extend_from_slice(b"](render:/?page=");
+    out.append(&u32_to_bytes(env, page));
+    if !categories.is_empty() {
+        out.extend_from_slice(b"&category=");
+        out.append(categories);
+    }
+    out.extend_from_slice(b")");
+    out
+}
+
+/// The categories selected by `?category=a,b`, each name URL-encoded.
+/// `all` and empty names select nothing; no selection shows every demo.
+fn selected_categories(env: &Env, query: &Bytes) -> Map<Bytes, ()> {
+    let mut selected = Map::new(env);
+    let Some(value) = query_param(query, b"category") else {
+        return selected;
+    };
+    let mut start = 0;
+    for i in 0..=value.len() {
+        if i == value.len() || value.get_unchecked(i) == b',' {
+            let name = url_decode(env, &value.slice(start..i));
+            if !name.is_empty() && name != Bytes::from_slice(env, b"all") {
+                selected.set(name, ());
+            }
+            start = i + 1;
+        }
+    }
+    selected
+}
+
+/// The `?category=` value for `selected`: the URL-encoded names in sorted
+/// order, joined by `,`
+fn category_param(env: &Env, selected: &Map<Bytes, ()>) -> Bytes {
+    let mut out = Bytes::new(env);
+    for (i, name) in sorted_keys(selected).iter().enumerate() {
+        if i > 0 {
+            out.push_back(b',');
+        }
+        out.append(&url_encode(env, &name));
+    }
+    out
+}
+
+/// Category filter chips: `All` plus each distinct category in sorted
+/// order. Each category chip toggles that category in the selection.
+fn category_nav(env: &Env, demos: &Vec<DemoInfo>, selected: &Map<Bytes, ()>) -> Bytes {
+    let mut categories: Map<Bytes, ()> = Map::new(env);
+    for demo in demos.iter() {
+        if !demo.category.is_empty() {
+            categories.set(string_to_bytes(env, &demo.category), ());
+        }
+    }
+
+    let mut chips = Vec::new(env);
+    chips.push_back((Bytes::from_slice(env, b"All"), selected.is_empty(), Bytes::from_slice(env, b"/")));
+    for category in sorted_keys(&categories).iter() {
+        let mut toggled = selected.clone();
+        let on = toggled.contains_key(category.clone());
+        if on {
+            toggled.remove(category.clone());
+        } else {
+            toggled.set(category.clone(), ());
+        }
+
+        let mut path = Bytes::from_slice(env, b"/");
+        if !toggled.is_empty() {
+            path.extend_from_slice(b"?category=");
+            path.append(&category_param(env, &toggled));
+        }
+        chips.push_back((category, on, path));
+    }
+
+    MarkdownBuilder::new(env)
+        .bold("Categories:")
+        .text(" ")
+        .filter_chips(&chips)
+        .build()
+}
+
+/// Render a markdown link to a contract on stellar.expert for the configured
+/// network (`mainnet`/`public`, `futurenet`, otherwise testnet), labelled with
+/// the shortened id, e.g. `[CABC…WXYZ](https://stellar.expert/...)`
+fn explorer_link(env: &Env, contract_id: &Bytes, network: &Bytes) -> Bytes {
+    let explorer_network: &[u8] = if *network == Bytes::from_slice(env, b"mainnet")
+        || *network == Bytes::from_slice(env, b"public")
+    {
+        b"public"
+    } else if *network == Bytes::from_slice(env, b"futurenet") {
+        b"futurenet"
+    } else {
+        b"testnet"
+    };
+
+    let len = contract_id.len();
+    let mut link = Bytes::from_slice(env, b"[");
+    if len > 8 {
+        link.append(&escape_markdown(env, &contract_id.slice(..4)));
+        link.extend_from_slice("…".as_bytes());
+        link.append(&escape_markdown(env, &contract_id.slice(len - 4..)));
+    } else {
+        link.append(&escape_markdown(env, contract_id));
+    }
+    link.extend_from_slice(b"](https://stellar.expert/explorer/");
+    link.extend_from_slice(explorer_network);
+    link.extend_from_slice(b"/contract/");
+    link.append(&url_encode(env, contract_id));
+    link.extend_from_slice(b")");
+    link
 }
 
 /// Convert a Soroban String to Bytes
@@ -39,47 +210,109 @@ pub struct HomepageContract;
 
 #[contractimpl]
 impl HomepageContract {
-    /// Initialize with demo contracts
-    pub fn init(env: Env, viewer_url: String, network: String) {
+    /// Initialize with demo contracts. `admin` is the only address allowed
+    /// to change the catalog afterwards.
+    pub fn init(env: Env, admin: Address, viewer_url: String, network: String) {
+        if env.storage().persistent().has(&DataKey::Admin) {
+            panic!("Already initialized");
+        }
+        env.storage().persistent().set(&DataKey::Admin, &admin);
         env.storage().persistent().set(&DataKey::ViewerUrl, &viewer_url);
         env.storage().persistent().set(&DataKey::Network, &network);
+        env.storage()
+            .persistent()
+            .set(&DataKey::InitializedAt, &env.ledger().timestamp());
 
-        let demos: Vec<DemoInfo> = Vec::new(&env);
-        env.storage().persistent().set(&DataKey::Demos, &demos);
+        Self::write_demos(&env, &Vec::new(&env));
     }
 
-    /// Add a demo contract
+    /// Add a demo contract under `category`, e.g. `forms`
     pub fn add_demo(
         env: Env,
         name: String,
         description: String,
         contract_id: String,
         features: String,
+        category: String,
     ) {
-        let mut demos: Vec<DemoInfo> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Demos)
-            .unwrap_or(Vec::new(&env));
+        Self::require_admin(&env);
+        let mut demos = Self::read_demos(&env);
 
         demos.push_back(DemoInfo {
             name,
             description,
             contract_id,
             features,
+            category,
         });
 
-        env.storage().persistent().set(&DataKey::Demos, &demos);
+        Self::write_demos(&env, &demos);
+    }
+
+    /// Remove the demo at `index`, shifting later demos up. Out-of-range
+    /// indexes are ignored.
+    pub fn remove_demo(env: Env, index: u32) {
+        Self::require_admin(&env);
+        let mut demos = Self::read_demos(&env);
+
+        if index < demos.len() {
+            demos.remove(index);
+            Self::write_demos(&env, &demos);
+        }
     }
 
     /// Clear all demos
     pub fn clear_demos(env: Env) {
-        let demos: Vec<DemoInfo> = Vec::new(&env);
-        env.storage().persistent().set(&DataKey::Demos, &demos);
+        Self::require_admin(&env);
+        Self::write_demos(&env, &Vec::new(&env));
+    }
+
+    /// Hand catalog management to `new_admin` (admin only)
+    pub fn transfer_admin(env: Env, new_admin: Address) {
+        Self::require_admin(&env);
+        env.storage().persistent().set(&DataKey::Admin, &new_admin);
     }
 
-    /// Render the homepage
-    pub fn render(env: Env, _path: Option<String>, _viewer: Option<Address>) -> Bytes {
+    fn require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Not initialized");
+        admin.require_auth();
+    }
+
+    /// Read the demo list, upgrading legacy entries with no category
+    fn read_demos(env: &Env) -> Vec<DemoInfo> {
+        let storage = env.storage().persistent();
+        if let Some(demos) = storage.get(&DataKey::DemosV2) {
+            return demos;
+        }
+
+        let legacy: Vec<LegacyDemoInfo> = storage.get(&DataKey::Demos).unwrap_or(Vec::new(env));
+        let mut demos: Vec<DemoInfo> = Vec::new(env);
+        for demo in legacy.iter() {
+            demos.push_back(DemoInfo {
+                name: demo.name,
+                description: demo.description,
+                contract_id: demo.contract_id,
+                features: demo.features,
+                category: String::from_str(env, ""),
+            });
+        }
+        demos
+    }
+
+    fn write_demos(env: &Env, demos: &Vec<DemoInfo>) {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::DemosV2, demos);
+        storage.remove(&DataKey::Demos);
+    }
+
+    /// Render the homepage. `?page=N` selects a page of demos and
+    /// `?category=a,b` shows only demos in any of the listed categories;
+    /// `all` or no category shows every demo.
+    pub fn render(env: Env, path: Option<String>, _viewer: Option<Address>) -> Bytes {
         let viewer_url: String = env
             .storage()
             .persistent()
@@ -92,16 +325,54 @@ impl HomepageContract {
             .get(&DataKey::Network)
             .unwrap_or(String::from_str(&env, "testnet"));
 
-        let demos: Vec<DemoInfo> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Demos)
-            .unwrap_or(Vec::new(&env));
+        let demos = Self::read_demos(&env);
+
+        let (_, query) = split_query(&env, &path_to_bytes(&env, &path));
+        let selected = selected_categories(&env, &query);
+        let categories = category_param(&env, &selected);
+
+        let mut shown: Vec<DemoInfo> = Vec::new(&env);
+        for demo in demos.iter() {
+            if selected.is_empty() || selected.contains_key(string_to_bytes(&env, &demo.category)) {
+                shown.push_back(demo);
+            }
+        }
+
+        let page_count = shown.len().div_ceil(DEMOS_PER_PAGE).max(1);
+        let page = query_param(&query, b"page")
+            .and_then(|v| bytes_to_u32(&v))
+            .unwrap_or(1)
+            .clamp(1, page_count);
+        let start = (page - 1) * DEMOS_PER_PAGE;
+        let end = (start + DEMOS_PER_PAGE).min(shown.len());
 
         let mut builder = MarkdownBuilder::new(&env);
 
         builder = builder
+            .image_data("Soroban Render", "image/svg+xml", &Bytes::from_slice(&env, LOGO_SVG))
+            .newline()
+            .newline()
             .h1("Soroban Render Demos")
+            .image_linked("Source on GitHub", SOURCE_BADGE, SOURCE_URL)
+            .newline()
+            .newline()
+            .bold("Network:")
+            .text(" ")
+            .code(network_name(&env))
+            .newline()
+            .newline();
+
+        let initialized_at: Option<u64> = env.storage().persistent().get(&DataKey::InitializedAt);
+        if let Some(init) = initialized_at {
+            builder = builder
+                .bold("Live for:")
+                .text(" ")
+                .age_since(&env, init)
+                .newline()
+                .newline();
+        }
+
+        builder = builder
             .paragraph("Welcome! These demos showcase what's possible when smart contracts render their own UI.")
             .paragraph("Each demo below is a live Soroban contract. Click to explore.")
             .hr();
@@ -111,18 +382,32 @@ impl HomepageContract {
                 .h2("No demos configured")
                 .paragraph("Use `add_demo` to register demo contracts.");
         } else {
-            for demo in demos.iter() {
+            builder = builder.raw(category_nav(&env, &demos, &selected));
+
+            if shown.is_empty() {
+                builder = builder.paragraph("*No demos in this category.*");
+            }
+
+            for demo in shown.slice(start..end).iter() {
                 // Build "## Name" header
                 builder = builder
                     .raw_str("## ")
-                    .raw(string_to_bytes(&env, &demo.name))
+                    .raw_escaped(&string_to_bytes(&env, &demo.name))
                     .newline()
                     .newline()
-                    .raw(string_to_bytes(&env, &demo.description))
+                    .raw_escaped(&string_to_bytes(&env, &demo.description))
                     .newline()
                     .newline()
                     .raw_str("**Features:** ")
-                    .raw(string_to_bytes(&env, &demo.features))
+                    .raw_escaped(&string_to_bytes(&env, &demo.features))
+                    .newline()
+                    .newline()
+                    .raw_str("**Contract:** ")
+                    .raw(explorer_link(
+                        &env,
+                        &string_to_bytes(&env, &demo.contract_id),
+                        &string_to_bytes(&env, &network),
+                    ))
                     .newline()
                     .newline();
 
@@ -132,14 +417,34 @@ impl HomepageContract {
                     .raw_str("[View Live Demo](")
                     .raw(string_to_bytes(&env, &viewer_url))
                     .raw_str("?contract=")
-                    .raw(string_to_bytes(&env, &demo.contract_id))
+                    .raw(url_encode(&env, &string_to_bytes(&env, &demo.contract_id)))
                     .raw_str("&network=")
-                    .raw(string_to_bytes(&env, &network))
+                    .raw(url_encode(&env, &string_to_bytes(&env, &network)))
                     .raw_str(")")
                     .newline()
                     .newline()
                     .hr();
             }
+
+            if page_count > 1 {
+                if page > 1 {
+                    builder = builder.raw(page_link(&env, "Previous", page - 1, &categories));
+                }
+                if page > 1 && page < page_count {
+                    builder = builder.text(" | ");
+                }
+                if page < page_count {
+                    builder = builder.raw(page_link(&env, "Next", page + 1, &categories));
+                }
+                builder = builder
+                    .text(" (page ")
+                    .number(page)
+                    .text(" of ")
+                    .number(page_count)
+                    .text(")")
+                    .newline()
+                    .newline();
+            }
         }
 
         builder = builder
@@ -165,6 +470,8 @@ impl HomepageContract {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+    use soroban_sdk::IntoVal;
     use soroban_sdk::Env;
 
     #[test]
@@ -175,23 +482,30 @@ mod tests {
         env.as_contract(&contract_id, || {
             HomepageContract::init(
                 env.clone(),
+                Address::generate(&env),
                 String::from_str(&env, "https://example.com/viewer/"),
                 String::from_str(&env, "testnet"),
             );
 
-            let result = HomepageContract::render(env.clone(), None, None);
-            assert!(!result.is_empty());
+            let (buf, len) = render_page(&env, "/");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.starts_with("![Soroban Render](data:image/svg+xml,%3Csvg%20"));
+            assert!(s.contains(
+                "[![Source on GitHub](https://img.shields.io/badge/source-GitHub-7857e1)](https://github.com/wyhaines/soroban-render)"
+            ));
         });
     }
 
     #[test]
     fn test_add_demo() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register(HomepageContract, ());
 
         env.as_contract(&contract_id, || {
             HomepageContract::init(
                 env.clone(),
+                Address::generate(&env),
                 String::from_str(&env, "https://example.com/"),
                 String::from_str(&env, "testnet"),
             );
@@ -202,6 +516,7 @@ mod tests {
                 String::from_str(&env, "A full-featured todo list"),
                 String::from_str(&env, "CABC123"),
                 String::from_str(&env, "Routing, forms, CRUD"),
+                String::from_str(&env, "apps"),
             );
 
             let result = HomepageContract::render(env.clone(), None, None);
@@ -218,4 +533,350 @@ mod tests {
             assert!(s.contains("CABC123"));
         });
     }
+
+    #[test]
+    fn test_network_badge() {
+        let env = Env::default();
+        let contract_id = env.register(HomepageContract, ());
+
+        env.as_contract(&contract_id, || {
+            // The default test env has no recognized network id
+            let result = HomepageContract::render(env.clone(), None, None);
+
+            let mut buf = [0u8; 2048];
+            let len = result.len() as usize;
+            for i in 0..len.min(2048) {
+                if let Some(b) = result.get(i as u32) {
+                    buf[i] = b;
+                }
+            }
+            let s = core::str::from_utf8(&buf[..len.min(2048)]).unwrap_or("");
+            assert!(s.contains("**Network:** `Unknown`"));
+            assert!(!s.contains("Live for:"));
+
+            env.ledger().set_network_id(TESTNET_NETWORK_ID);
+            assert_eq!(network_name(&env), "Testnet");
+            env.ledger().set_network_id(MAINNET_NETWORK_ID);
+            assert_eq!(network_name(&env), "Mainnet");
+        });
+    }
+
+    #[test]
+    fn test_live_for() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000_000);
+        let contract_id = env.register(HomepageContract, ());
+
+        env.as_contract(&contract_id, || {
+            init_homepage(&env);
+            env.ledger().set_timestamp(1_000_000 + 3 * 86_400 + 60);
+
+            let (buf, len) = render_page(&env, "/");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains("**Live for:** 3 days"));
+        });
+    }
+
+    #[test]
+    fn test_explorer_link() {
+        let env = Env::default();
+        let id = Bytes::from_slice(
+            &env,
+            b"CCYEOY2JTOQ2JIMLLERAFNHAVKEKMEJDBOTLN6DIIWBHWEIMUA2T2VY4",
+        );
+
+        let link = explorer_link(&env, &id, &Bytes::from_slice(&env, b"testnet"));
+        let mut buf = [0u8; 256];
+        let len = link.len() as usize;
+        link.copy_into_slice(&mut buf[..len]);
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        assert_eq!(
+            s,
+            "[CCYE…2VY4](https://stellar.expert/explorer/testnet/contract/CCYEOY2JTOQ2JIMLLERAFNHAVKEKMEJDBOTLN6DIIWBHWEIMUA2T2VY4)"
+        );
+
+        let link = explorer_link(&env, &id, &Bytes::from_slice(&env, b"mainnet"));
+        let len = link.len() as usize;
+        link.copy_into_slice(&mut buf[..len]);
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(s.contains("stellar.expert/explorer/public/contract/CCYEOY2J"));
+    }
+
+    #[test]
+    fn test_demo_fields_escaped() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HomepageContract, ());
+
+        env.as_contract(&contract_id, || {
+            HomepageContract::init(
+                env.clone(),
+                Address::generate(&env),
+                String::from_str(&env, "https://example.com/"),
+                String::from_str(&env, "test net"),
+            );
+
+            HomepageContract::add_demo(
+                env.clone(),
+                String::from_str(&env, "Tips & Tricks #1"),
+                String::from_str(&env, "[Not a link](tx:evil)"),
+                String::from_str(&env, "CABC#1&x=y"),
+                String::from_str(&env, "*bold*"),
+                String::from_str(&env, "misc"),
+            );
+
+            let result = HomepageContract::render(env.clone(), None, None);
+
+            let mut buf = [0u8; 4096];
+            let len = result.len() as usize;
+            for i in 0..len.min(4096) {
+                if let Some(b) = result.get(i as u32) {
+                    buf[i] = b;
+                }
+            }
+            let s = core::str::from_utf8(&buf[..len.min(4096)]).unwrap_or("");
+            assert!(s.contains("## Tips \\& Tricks \\#1"));
+            assert!(s.contains("\\[Not a link\\]\\(tx:evil\\)"));
+            assert!(s.contains("**Features:** \\*bold\\*"));
+            assert!(s.contains(
+                "[View Live Demo](https://example.com/?contract=CABC%231%26x%3Dy&network=test%20net)"
+            ));
+            assert!(s.contains("/contract/CABC%231%26x%3Dy)"));
+        });
+    }
+
+    fn render_page(env: &Env, path: &str) -> ([u8; 4096], usize) {
+        let result = HomepageContract::render(env.clone(), Some(String::from_str(env, path)), None);
+        let mut buf = [0u8; 4096];
+        let len = (result.len() as usize).min(4096);
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        (buf, len)
+    }
+
+    fn init_homepage(env: &Env) -> Address {
+        let admin = Address::generate(env);
+        HomepageContract::init(
+            env.clone(),
+            admin.clone(),
+            String::from_str(env, "https://example.com/"),
+            String::from_str(env, "testnet"),
+        );
+        admin
+    }
+
+    fn add_numbered_demos(env: &Env, count: u32) {
+        let names = ["Demo A", "Demo B", "Demo C", "Demo D", "Demo E", "Demo F", "Demo G"];
+        for name in names.iter().take(count as usize) {
+            HomepageContract::add_demo(
+                env.clone(),
+                String::from_str(env, name),
+                String::from_str(env, "A demo"),
+                String::from_str(env, "CABC123"),
+                String::from_str(env, "Rendering"),
+                String::from_str(env, "basics"),
+            );
+        }
+    }
+
+    #[test]
+    fn test_demo_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HomepageContract, ());
+
+        env.as_contract(&contract_id, || {
+            init_homepage(&env);
+            add_numbered_demos(&env, 7);
+
+            let (buf, len) = render_page(&env, "/");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains("## Demo E"));
+            assert!(!s.contains("## Demo F"));
+            assert!(s.contains("[Next](render:/?page=2) (page 1 of 2)"));
+            assert!(!s.contains("[Previous]"));
+
+            let (buf, len) = render_page(&env, "/?page=2");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(!s.contains("## Demo E"));
+            assert!(s.contains("## Demo F"));
+            assert!(s.contains("## Demo G"));
+            assert!(s.contains("[Previous](render:/?page=1) (page 2 of 2)"));
+            assert!(!s.contains("[Next]"));
+
+            // Past the end clamps to the last page
+            let (buf, len) = render_page(&env, "/?page=9");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains("## Demo G"));
+
+            // Exactly one page of demos has no page links
+            HomepageContract::remove_demo(env.clone(), 6);
+            HomepageContract::remove_demo(env.clone(), 5);
+            let (buf, len) = render_page(&env, "/");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(!s.contains("(page "));
+        });
+    }
+
+    #[test]
+    fn test_remove_demo() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HomepageContract, ());
+
+        env.as_contract(&contract_id, || {
+            init_homepage(&env);
+            add_numbered_demos(&env, 3);
+            HomepageContract::remove_demo(env.clone(), 1);
+            // Out of range is ignored
+            HomepageContract::remove_demo(env.clone(), 5);
+
+            let (buf, len) = render_page(&env, "/");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains("## Demo A"));
+            assert!(!s.contains("## Demo B"));
+            assert!(s.contains("## Demo C"));
+            assert!(s.find("## Demo A").unwrap() < s.find("## Demo C").unwrap());
+        });
+    }
+
+    #[test]
+    fn test_category_filter() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(HomepageContract, ());
+
+        env.as_contract(&contract_id, || {
+            init_homepage(&env);
+            // Listed in sorted order, not the order they were added
+            let demos = [("Form Kit", "forms"), ("Todo", "apps"), ("Notes", "apps")];
+            for (name, category) in demos {
+                HomepageContract::add_demo(
+                    env.clone(),
+                    String::from_str(&env, name),
+                    String::from_str(&env, "A demo"),
+                    String::from_str(&env, "CABC123"),
+                    String::from_str(&env, "Rendering"),
+                    String::from_str(&env, category),
+                );
+            }
+
+            let (buf, len) = render_page(&env, "/");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains(
+                "**Categories:** **[✓ All](render:/)** [apps](render:/?category=apps) [forms](render:/?category=forms)\n\n"
+            ));
+            assert!(s.contains("## Todo") && s.contains("## Form Kit") && s.contains("## Notes"));
+
+            // Each chip toggles its category in the selection
+            let (buf, len) = render_page(&env, "/?category=apps");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains(
+                "**Categories:** [All](render:/) **[✓ apps](render:/)** [forms](render:/?category=apps,forms)"
+            ));
+            assert!(s.contains("## Todo"));
+            assert!(s.contains("## Notes"));
+            assert!(!s.contains("## Form Kit"));
+
+            let (buf, len) = render_page(&env, "/?category=forms,apps");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains(
+                "[All](render:/) **[✓ apps](render:/?category=forms)** **[✓ forms](render:/?category=apps)**"
+            ));
+            assert!(s.contains("## Todo") && s.contains("## Form Kit") && s.contains("## Notes"));
+
+            let (buf, len) = render_page(&env, "/?category=all");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains("## Form Kit"));
+
+            let (buf, len) = render_page(&env, "/?category=games");
+            let s = core::str::from_utf8(&buf[..len]).unwrap();
+            assert!(s.contains("No demos in this category."));
+        });
+    }
+
+    fn demo_args(env: &Env) -> (String, String, String, String, String) {
+        (
+            String::from_str(env, "Spam"),
+            String::from_str(env, "Not a demo"),
+            String::from_str(env, "CABC123"),
+            String::from_str(env, "None"),
+            String::from_str(env, "misc"),
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_demo_requires_admin() {
+        let env = Env::default();
+        let contract_id = env.register(HomepageContract, ());
+        let client = HomepageContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let intruder = Address::generate(&env);
+        client.init(
+            &admin,
+            &String::from_str(&env, "https://example.com/"),
+            &String::from_str(&env, "testnet"),
+        );
+
+        // The intruder signs the call, but the contract asks for the admin
+        let (name, description, demo_id, features, category) = demo_args(&env);
+        client
+            .mock_auths(&[MockAuth {
+                address: &intruder,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "add_demo",
+                    args: (
+                        name.clone(),
+                        description.clone(),
+                        demo_id.clone(),
+                        features.clone(),
+                        category.clone(),
+                    )
+                        .into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .add_demo(&name, &description, &demo_id, &features, &category);
+    }
+
+    #[test]
+    fn test_admin_manages_demos() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(HomepageContract, ());
+        let client = HomepageContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.init(
+            &admin,
+            &String::from_str(&env, "https://example.com/"),
+            &String::from_str(&env, "testnet"),
+        );
+
+        let (name, description, demo_id, features, category) = demo_args(&env);
+        client.add_demo(&name, &description, &demo_id, &features, &category);
+        assert_eq!(env.auths()[0].0, admin);
+
+        let new_admin = Address::generate(&env);
+        client.transfer_admin(&new_admin);
+        client.clear_demos();
+        assert_eq!(env.auths()[0].0, new_admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already initialized")]
+    fn test_init_twice() {
+        let env = Env::default();
+        let contract_id = env.register(HomepageContract, ());
+        let client = HomepageContractClient::new(&env, &contract_id);
+        let viewer_url = String::from_str(&env, "https://example.com/");
+        let network = String::from_str(&env, "testnet");
+        client.init(&Address::generate(&env), &viewer_url, &network);
+        client.init(&Address::generate(&env), &viewer_url, &network);
+    }
 }
@@ -1,9 +1,73 @@
 #![no_std]
 use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_render_common::meta::manifest;
+use soroban_render_common::style::StyleRules;
 use soroban_render_sdk::prelude::*;
 
 // Metadata for render support with styles
 soroban_render!(markdown, styles);
+soroban_render_common::render_styles!(light, dark);
+
+/// Render components this contract exports, as (function, description).
+/// Exported functions can't be introspected on-chain, so the catalog is
+/// declared here, beside the functions it lists, and `render` builds from it.
+const COMPONENTS: [(&str, &str); 3] = [
+    ("render_header", "App header with branding"),
+    ("render_footer", "App footer with credits"),
+    ("render_nav", "Navigation component"),
+];
+
+/// `var(--name, fallback)` as a string literal, for use inside `concat!`.
+/// The fallback keeps a rule usable when an including contract hasn't
+/// loaded the theme's `:root` variables.
+macro_rules! var_ref {
+    ($name:literal, $fallback:literal) => {
+        concat!("var(--", $name, ", ", $fallback, ")")
+    };
+}
+
+/// Declarations for an equal-width grid of `$columns` columns with `$gap`
+/// spacing, as a string literal
+macro_rules! grid {
+    ($columns:literal, $gap:literal) => {
+        concat!(
+            "display: grid; grid-template-columns: repeat(", $columns, ", 1fr); gap: ", $gap, ";"
+        )
+    };
+}
+
+/// Format Unix seconds as `YYYY-MM-DD HH:MM:SS UTC`, using integer
+/// civil-from-days date math (proleptic Gregorian, years 1970-9999)
+fn format_timestamp(env: &Env, secs: u64) -> Bytes {
+    let days = secs / 86_400;
+    let rem = secs % 86_400;
+
+    // Shift the epoch to 0000-03-01 so leap days fall at the end of a year
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let mut out = *b"0000-00-00 00:00:00 UTC";
+    let mut put = |at: usize, width: usize, mut value: u64| {
+        for i in (at..at + width).rev() {
+            out[i] = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+    };
+    put(0, 4, year);
+    put(5, 2, month);
+    put(8, 2, day);
+    put(11, 2, rem / 3_600);
+    put(14, 2, rem % 3_600 / 60);
+    put(17, 2, rem % 60);
+    Bytes::from_slice(env, &out)
+}
 
 #[contract]
 pub struct ThemeContract;
@@ -48,18 +112,35 @@ impl ThemeContract {
             .root_vars_end()
             .newline()
             // Base element styles
-            .rule("body", "font-family: var(--font-family); color: var(--text); background: var(--bg); line-height: 1.6;")
+            .rule("body", concat!(
+                "font-family: var(--font-family); color: ", var_ref!("text", "#171717"),
+                "; background: ", var_ref!("bg", "#ffffff"), "; line-height: 1.6;"
+            ))
             .rule("h1", "font-size: 1.875rem; font-weight: 600; border-bottom: 1px solid var(--border); padding-bottom: 0.75rem; margin: 0 0 1rem 0; letter-spacing: -0.02em;")
             .rule("h2", "font-size: 1.5rem; font-weight: 600; margin: 2rem 0 1rem 0; letter-spacing: -0.01em;")
             .rule("h3", "font-size: 1.25rem; font-weight: 600; margin: 1.5rem 0 0.75rem 0;")
             .rule("h4", "font-size: 1.125rem; font-weight: 500; margin: 1.25rem 0 0.5rem 0;")
-            .rule("a", "color: var(--primary); text-decoration: none; transition: color 100ms ease-out;")
-            .rule("a:hover", "color: var(--primary-hover); text-decoration: underline;")
+            .rule_with_states(
+                "a",
+                concat!("color: ", var_ref!("primary", "#7857e1"), "; text-decoration: none; transition: color 100ms ease-out;"),
+                &[("hover", concat!("color: ", var_ref!("primary-hover", "#6b4ad1"), "; text-decoration: underline;"))],
+            )
             .rule("code", "font-family: var(--font-mono); background: var(--bg-muted); padding: 0.15rem 0.4rem; border-radius: 4px; font-size: 0.9em; color: var(--sds-clr-lilac-11);")
-            .rule("pre", "background: var(--bg-muted); padding: 1rem; border-radius: 6px; overflow-x: auto; border: 1px solid var(--border);")
-            .rule("pre code", "background: transparent; padding: 0; color: inherit; font-size: 0.875rem;")
+            .nested(
+                "pre",
+                "background: var(--bg-muted); padding: 1rem; border-radius: 6px; overflow-x: auto; border: 1px solid var(--border);",
+                &[("code", "background: transparent; padding: 0; color: inherit; font-size: 0.875rem;")],
+            )
             .rule("blockquote", "margin: 1rem 0; padding: 0.75rem 1rem; border-left: 3px solid var(--primary); background: var(--sds-clr-gray-02); border-radius: 0 4px 4px 0;")
             .rule("hr", "border: none; border-top: 1px solid var(--border); margin: 2rem 0;")
+            // Column layout for `:::columns` content
+            .rule(".columns", grid!(2, "1.5rem"))
+            // Status badges
+            .rule(".badge", "display: inline-block; padding: 0.1rem 0.5rem; border-radius: 999px; font-size: 0.75rem; font-weight: 500; line-height: 1.4; vertical-align: middle;")
+            .rule(".badge-success", concat!("background: ", var_ref!("success", "#30a46c"), "; color: #ffffff;"))
+            .rule(".badge-warning", concat!("background: ", var_ref!("warning", "#ffc53d"), "; color: ", var_ref!("sds-clr-gray-12", "#171717"), ";"))
+            .rule(".badge-danger", concat!("background: ", var_ref!("danger", "#e5484d"), "; color: #ffffff;"))
+            .rule(".badge-neutral", "background: var(--bg-muted); color: var(--text-muted); border: 1px solid var(--border);")
             .build()
     }
 
@@ -94,19 +175,37 @@ impl ThemeContract {
             .build()
     }
 
+    /// Describe what this contract renders for viewers
+    pub fn render_manifest(env: Env) -> Bytes {
+        manifest(&env, &["markdown"], &["/"], true, true)
+    }
+
     /// Main render function - returns available components list
     pub fn render(env: Env, _path: Option<String>, _viewer: Option<Address>) -> Bytes {
-        MarkdownBuilder::new(&env)
+        let mut md = MarkdownBuilder::new(&env)
             .h1("Soroban Render Theme Components")
             .paragraph("This contract provides reusable UI components for Soroban Render apps.")
-            .h2("Available Components")
-            .list_item("`render_header` - App header with branding")
-            .list_item("`render_footer` - App footer with credits")
-            .list_item("`render_nav` - Navigation component")
+            .h2("Available Components");
+        for (func, description) in COMPONENTS {
+            md = md
+                .raw_str("- `")
+                .raw_str(func)
+                .raw_str("` - ")
+                .raw_str(description)
+                .newline();
+        }
+
+        md = md
             .h2("Usage")
             .paragraph("Include these in your contract's render output:")
-            .raw_str("```\n{{include contract=THEME_CONTRACT_ID func=\"header\"}}\n{{include contract=THEME_CONTRACT_ID func=\"nav\"}}\n{{include contract=THEME_CONTRACT_ID func=\"footer\"}}\n```\n")
-            .build()
+            .raw_str("```\n");
+        for (func, _) in COMPONENTS {
+            md = md
+                .raw_str("{{include contract=THEME_CONTRACT_ID func=\"")
+                .raw_str(func.strip_prefix("render_").unwrap_or(func))
+                .raw_str("\"}}\n");
+        }
+        md.raw_str("```\n").build()
     }
 
     /// Render header component
@@ -128,6 +227,9 @@ impl ThemeContract {
             .h3("How This Works")
             .paragraph("This UI comes directly from the smart contract's `render()` function. The contract returns markdown with special protocols (`render:`, `tx:`, `form:`) that enable navigation and transactions. No separate frontend deployment needed - the contract IS the app.")
             .paragraph("*Powered by [Soroban Render](https://github.com/wyhaines/soroban-render)* | Built on [Stellar](https://stellar.org)")
+            .raw_str("*Last updated: ")
+            .raw(format_timestamp(&env, env.ledger().timestamp()))
+            .raw_str("*\n\n")
             .build()
     }
 
@@ -147,7 +249,9 @@ impl ThemeContract {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::Env;
+    use soroban_render_common::style::minify_css;
+    use soroban_sdk::testutils::Ledger;
+    use soroban_sdk::{vec, Env, IntoVal, Symbol};
 
     #[test]
     fn test_render_header() {
@@ -183,6 +287,143 @@ mod test {
         assert!(result_str.contains("Powered by"));
     }
 
+    #[test]
+    fn test_styles_minified() {
+        let env = Env::default();
+        let pretty = ThemeContract::styles(env.clone());
+        let minified = minify_css(&env, &pretty);
+        assert!(minified.len() < pretty.len());
+
+        // Same number of rules, and no comments or trailing newline
+        let count = |css: &Bytes, c: u8| css.iter().filter(|b| *b == c).count();
+        assert_eq!(count(&minified, b'{'), count(&pretty, b'{'));
+        assert_eq!(count(&minified, b'}'), count(&pretty, b'}'));
+        assert_eq!(count(&minified, b'\n'), 0);
+        assert!(!minified.iter().any(|b| b == b'*'));
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        let env = Env::default();
+        assert_eq!(
+            format_timestamp(&env, 0),
+            Bytes::from_slice(&env, b"1970-01-01 00:00:00 UTC")
+        );
+        // Leap day
+        assert_eq!(
+            format_timestamp(&env, 951_782_400),
+            Bytes::from_slice(&env, b"2000-02-29 00:00:00 UTC")
+        );
+        assert_eq!(
+            format_timestamp(&env, 1_700_000_000),
+            Bytes::from_slice(&env, b"2023-11-14 22:13:20 UTC")
+        );
+        // Last second of a year
+        assert_eq!(
+            format_timestamp(&env, 1_735_689_599),
+            Bytes::from_slice(&env, b"2024-12-31 23:59:59 UTC")
+        );
+    }
+
+    #[test]
+    fn test_render_footer_timestamp() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        let result = ThemeContract::render_footer(env.clone(), None, None);
+
+        let mut buf: [u8; 1024] = [0; 1024];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let result_str = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(result_str.contains("*Last updated: 2023-11-14 22:13:20 UTC*"));
+    }
+
+    #[test]
+    fn test_render_component_catalog() {
+        let env = Env::default();
+        let contract_id = env.register(ThemeContract, ());
+        let client = ThemeContractClient::new(&env, &contract_id);
+        let result = client.render(&None, &None);
+
+        let mut buf: [u8; 1024] = [0; 1024];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let result_str = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(result_str.contains("- `render_header` - App header with branding"));
+        assert!(result_str.contains("- `render_nav` - Navigation component"));
+        assert!(result_str.contains("{{include contract=THEME_CONTRACT_ID func=\"footer\"}}"));
+
+        // Every declared component must be a real export
+        for (func, _) in COMPONENTS {
+            let args = vec![&env, None::<String>.into_val(&env), None::<Address>.into_val(&env)];
+            let output: Bytes = env.invoke_contract(&contract_id, &Symbol::new(&env, func), args);
+            assert!(!output.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_styles_badges() {
+        let env = Env::default();
+        let result = ThemeContract::styles(env.clone());
+
+        let mut buf: [u8; 8192] = [0; 8192];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let result_str = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(result_str.contains(".badge {"));
+        assert!(result_str.contains(".badge-success {"));
+        assert!(result_str.contains(".badge-warning {"));
+    }
+
+    #[test]
+    fn test_styles_var_fallbacks() {
+        let env = Env::default();
+        let result = ThemeContract::styles(env.clone());
+
+        let mut buf: [u8; 8192] = [0; 8192];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let result_str = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(result_str.contains("color: var(--primary, #7857e1);"));
+        assert!(result_str.contains("background: var(--danger, #e5484d);"));
+        assert_eq!(var_ref!("bg", "#fff"), "var(--bg, #fff)");
+    }
+
+    #[test]
+    fn test_styles_columns_grid() {
+        let env = Env::default();
+        let result = ThemeContract::styles(env.clone());
+
+        let mut buf: [u8; 8192] = [0; 8192];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let result_str = core::str::from_utf8(&buf[..len]).unwrap();
+        assert!(result_str.contains(".columns {"));
+        assert!(result_str.contains(
+            "display: grid; grid-template-columns: repeat(2, 1fr); gap: 1.5rem;"
+        ));
+    }
+
     #[test]
     fn test_render_nav() {
         let env = Env::default();
@@ -199,4 +440,18 @@ mod test {
         assert!(result_str.contains("[Home]"));
         assert!(result_str.contains("[Tasks]"));
     }
+
+    #[test]
+    fn test_render_manifest() {
+        let env = Env::default();
+        let result = ThemeContract::render_manifest(env.clone());
+
+        assert_eq!(
+            result,
+            Bytes::from_slice(
+                &env,
+                br#"{"formats":["markdown"],"routes":["/"],"styles":true,"styles_dark":true}"#
+            )
+        );
+    }
 }
@@ -1,20 +1,52 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map, String, Vec};
+use core::cmp::Ordering;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, Map, String,
+    Symbol, Vec,
+};
+use soroban_chonk::prelude::*;
+use soroban_render_common::address::address_short;
+use soroban_render_common::collections::sort_by;
+use soroban_render_common::diff::diff_render;
+use soroban_render_common::escape::escape_markdown;
+use soroban_render_common::form::FormControls;
+use soroban_render_common::format::{Format, RenderFormat};
+use soroban_render_common::include::{include_arg, Include};
+use soroban_render_common::inline::{badge, BadgeKind, Inline};
+use soroban_render_common::json;
+use soroban_render_common::links::LinkRefMode;
+use soroban_render_common::nav::{NavBuilder, NavItem, NavLinks};
+use soroban_render_common::numbers::{percent_to_bytes, progress_bar, u32_to_bytes_grouped};
+use soroban_render_common::page::{page, Pages};
+use soroban_render_common::query::{query_param, split_query, url_decode, url_encode};
+use soroban_render_common::slots::{SlotMarkers, Slots};
+use soroban_render_common::table::Tables;
+use soroban_render_common::tx::TxLinks;
+use soroban_render_common::viewer::Viewer;
 use soroban_render_sdk::prelude::*;
 
 // Declare render support with styles and theme contract reference
 soroban_render!(markdown, json, styles, theme = "CCYEOY2JTOQ2JIMLLERAFNHAVKEKMEJDBOTLN6DIIWBHWEIMUA2T2VY4");
+soroban_render_common::render_styles!(light);
 
 // Storage keys
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Tasks(Address),    // Map<u32, Task> for each user
-    NextId(Address),   // Next task ID for each user
-    UserCount,         // Total unique users
-    TotalTasks,        // Total tasks across all users
-    HasTasks(Address), // Whether a user has ever had tasks (for counting unique users)
+    Tasks(Address),       // Legacy Map<u32, LegacyTask>, migrated to TasksV2 on write
+    NextId(Address),      // Next task ID for each user
+    UserCount,            // Total unique users
+    TotalTasks,           // Total tasks across all users
+    HasTasks(Address),    // Whether a user has ever had tasks (for counting unique users)
+    TasksV2(Address),     // Map<u32, Task> for each user
+    Admin,                // Address allowed to change contract settings
+    ThemeContract,        // Theme contract used for header/footer includes
+    CompletedTasks,       // Completed tasks across all users
+    User(u32),            // Address of the nth listed user, in order of their first task
+    ListedUsers,          // Number of User(n) entries
+    Leaderboard,          // Vec<LeaderboardEntry> of the users with the most tasks
+    NoticeSeen(Address),  // Whether a user has acknowledged the public-data notice
 }
 
 #[contracttype]
@@ -24,19 +56,451 @@ pub struct Task {
     pub description: String,
     pub completed: bool,
     pub owner: Address,
+    pub due: Option<u64>, // Ledger timestamp the task is due by
+    pub priority: u32,    // 0 = none, 1 = low, 2 = medium, 3+ = high
+}
+
+/// Task layout stored before `due` and `priority` existed
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacyTask {
+    pub id: u32,
+    pub description: String,
+    pub completed: bool,
+    pub owner: Address,
+}
+
+/// One user's standing on the leaderboard
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    pub user: Address,
+    pub tasks: u32,
+    pub completed: u32,
 }
 
 #[contract]
 pub struct TodoContract;
 
-// Theme contract ID for includes
+/// Users kept on the leaderboard
+const LEADERBOARD_SIZE: u32 = 10;
+
+// Default theme contract ID for includes, used until `set_theme` is called
 const THEME_CONTRACT_ID: &str = "CCYEOY2JTOQ2JIMLLERAFNHAVKEKMEJDBOTLN6DIIWBHWEIMUA2T2VY4";
 
+/// Source repository, linked from the About page and the footer
+const REPO_URL: &str = "https://github.com/wyhaines/soroban-render";
+
+/// Render paths this contract serves, as (path, title), listed by
+/// `/_sitemap`. Keep in step with the routes in `render`.
+const ROUTES: [(&str, &str); 9] = [
+    ("/", "Home"),
+    ("/about", "About"),
+    ("/leaderboard", "Leaderboard"),
+    ("/tasks", "All tasks"),
+    ("/tasks/pending", "Pending tasks"),
+    ("/tasks/completed", "Completed tasks"),
+    ("/task/{id}", "Task details"),
+    ("/task/{id}/edit", "Edit task"),
+    ("/task/{id}/delete", "Delete confirmation"),
+];
+
+/// Chunk collection name for a user's stored task rows: `tasks_` and the
+/// first 12 bytes of the SHA-256 of their address, in hex, which fits the
+/// 32-character `Symbol` limit that a full address would not
+fn task_rows_name(env: &Env, user: &Address) -> [u8; 30] {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let digest = env
+        .crypto()
+        .sha256(&string_to_bytes(env, &user.to_string()))
+        .to_array();
+    let mut name = *b"tasks_000000000000000000000000";
+    for (i, b) in digest[..12].iter().enumerate() {
+        name[6 + 2 * i] = HEX[(b >> 4) as usize];
+        name[7 + 2 * i] = HEX[(b & 0x0f) as usize];
+    }
+    name
+}
+
+/// Add `delta` to a stored global count, never going below zero
+fn adjust_count(env: &Env, key: &DataKey, delta: i32) {
+    let count: u32 = env.storage().persistent().get(key).unwrap_or(0);
+    let count = count.saturating_add_signed(delta);
+    env.storage().persistent().set(key, &count);
+}
+
+/// `{"id":N}`, the arguments of the task methods that take only an id
+fn id_args(env: &Env, id: u32) -> Bytes {
+    let mut args = Bytes::from_slice(env, b"{\"id\":");
+    args.append(&u32_to_bytes(env, id));
+    args.extend_from_slice(b"}");
+    args
+}
+
+/// ` › <label> #N`, the nav's `title` slot on the task pages
+fn task_title(env: &Env, label: &str, id: u32) -> Bytes {
+    let mut title = Bytes::from_slice(env, " › ".as_bytes());
+    title.extend_from_slice(label.as_bytes());
+    title.extend_from_slice(b" #");
+    title.append(&u32_to_bytes(env, id));
+    title
+}
+
+/// The theme contract set with `set_theme`, or the default theme
+fn theme_contract(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ThemeContract)
+        .unwrap_or_else(|| Address::from_str(env, THEME_CONTRACT_ID))
+}
+
+/// Completion filter selected by a task list route
+fn list_filter(route: &Bytes) -> Option<bool> {
+    if path_eq(route, b"/tasks/pending") || path_eq(route, b"/pending") {
+        Some(false)
+    } else if path_eq(route, b"/tasks/completed") || path_eq(route, b"/completed") {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// The All / Pending / Completed filter items, with `filter` active
+fn filter_nav_items(filter: Option<bool>) -> [NavItem<'static>; 3] {
+    [
+        NavItem {
+            label: "All",
+            path: "/tasks",
+            json_path: "/json",
+            active: filter.is_none(),
+        },
+        NavItem {
+            label: "Pending",
+            path: "/tasks/pending",
+            json_path: "/json/pending",
+            active: filter == Some(false),
+        },
+        NavItem {
+            label: "Completed",
+            path: "/tasks/completed",
+            json_path: "/json/completed",
+            active: filter == Some(true),
+        },
+    ]
+}
+
+/// Message for an empty task list, depending on whether a filter or search
+/// narrowed it
+fn empty_tasks_message(filtered: bool) -> &'static str {
+    if filtered {
+        "No matching tasks."
+    } else {
+        "No tasks yet. Add one above!"
+    }
+}
+
+/// Task table columns ahead of the actions column, matching
+/// `render_task_row`, with the `?sort=` key of each sortable one
+const TASK_COLUMNS: [(&str, &str); 4] =
+    [("#", "id"), ("Status", ""), ("Task", ""), ("Priority", "priority")];
+
+/// Tasks per page when no `per_page` is given
+const DEFAULT_PER_PAGE: u32 = 10;
+
+/// Upper bound on `per_page`, so a single page stays within the viewer's buffer
+const MAX_PER_PAGE: u32 = 50;
+
+/// Task list ordering selected by `?sort=`
+#[derive(Clone, Copy, PartialEq)]
+enum SortOrder {
+    /// Oldest first (the default)
+    Id,
+    /// Newest first
+    IdDesc,
+    /// Pending before completed, oldest first within each group
+    Status,
+    /// Highest priority first, oldest first within a priority
+    Priority,
+    /// Lowest priority first, oldest first within a priority
+    PriorityLow,
+}
+
+impl SortOrder {
+    fn from_bytes(value: &Bytes) -> Self {
+        // `id_desc` is the spelling from before the sortable table headers
+        if path_eq(value, b"-id") || path_eq(value, b"id_desc") {
+            SortOrder::IdDesc
+        } else if path_eq(value, b"status") {
+            SortOrder::Status
+        } else if path_eq(value, b"priority") {
+            SortOrder::Priority
+        } else if path_eq(value, b"-priority") {
+            SortOrder::PriorityLow
+        } else {
+            SortOrder::Id
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Id => "id",
+            SortOrder::IdDesc => "-id",
+            SortOrder::Status => "status",
+            SortOrder::Priority => "priority",
+            SortOrder::PriorityLow => "-priority",
+        }
+    }
+
+    /// How `a` and `b` compare in this order
+    fn compare(&self, a: &Task, b: &Task) -> Ordering {
+        match self {
+            SortOrder::Id => a.id.cmp(&b.id),
+            SortOrder::IdDesc => b.id.cmp(&a.id),
+            SortOrder::Status => (a.completed, a.id).cmp(&(b.completed, b.id)),
+            SortOrder::Priority => (b.priority, a.id).cmp(&(a.priority, b.id)),
+            SortOrder::PriorityLow => (a.priority, a.id).cmp(&(b.priority, b.id)),
+        }
+    }
+}
+
+/// Paging and ordering options parsed from the task list query string
+struct ListQuery {
+    /// 1-based page number
+    page: u32,
+    per_page: u32,
+    sort: SortOrder,
+    /// Raw `q` value, still URL-encoded
+    search: Option<Bytes>,
+    /// Raw `msg` value to show once as a toast; never carried by page links
+    message: Option<Bytes>,
+}
+
+impl ListQuery {
+    fn parse(query: &Bytes) -> Self {
+        let page = query_param(query, b"page")
+            .and_then(|v| bytes_to_u32(&v))
+            .filter(|p| *p > 0)
+            .unwrap_or(1);
+        let per_page = query_param(query, b"per_page")
+            .and_then(|v| bytes_to_u32(&v))
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_PER_PAGE)
+            .min(MAX_PER_PAGE);
+        let sort = query_param(query, b"sort")
+            .map(|v| SortOrder::from_bytes(&v))
+            .unwrap_or(SortOrder::Id);
+        let search = query_param(query, b"q").filter(|v| !v.is_empty());
+        let message = query_param(query, b"msg").filter(|v| !v.is_empty());
+        ListQuery {
+            page,
+            per_page,
+            sort,
+            search,
+            message,
+        }
+    }
+}
+
+/// Priority picker choices on the add-task form, as `(value, label)`
+const PRIORITY_OPTIONS: [(&str, &str); 4] =
+    [("0", "No priority"), ("1", "Low"), ("2", "Medium"), ("3", "High")];
+
+/// Label for a task priority, or `None` when the task has no priority
+fn priority_label(priority: u32) -> Option<&'static str> {
+    match priority {
+        0 => None,
+        1 => Some("low"),
+        2 => Some("medium"),
+        _ => Some("high"),
+    }
+}
+
+/// Whether an open task is past its due date
+fn is_overdue(env: &Env, task: &Task) -> bool {
+    match task.due {
+        Some(due) => !task.completed && env.ledger().timestamp() > due,
+        None => false,
+    }
+}
+
+/// Describe `ts` relative to the ledger time: "just now" within a minute,
+/// else "N minutes/hours/days ago" or "in N ..." for future times
+fn relative_time(env: &Env, ts: u64) -> Bytes {
+    let now = env.ledger().timestamp();
+    let (delta, future) = if ts > now {
+        (ts - now, true)
+    } else {
+        (now - ts, false)
+    };
+    if delta < 60 {
+        return Bytes::from_slice(env, b"just now");
+    }
+
+    let (count, unit) = if delta < 3_600 {
+        (delta / 60, "minute")
+    } else if delta < 86_400 {
+        (delta / 3_600, "hour")
+    } else {
+        (delta / 86_400, "day")
+    };
+
+    let mut out = Bytes::new(env);
+    if future {
+        out.extend_from_slice(b"in ");
+    }
+    out.append(&u64_to_bytes(env, count));
+    out.extend_from_slice(b" ");
+    out.extend_from_slice(unit.as_bytes());
+    if count != 1 {
+        out.extend_from_slice(b"s");
+    }
+    if !future {
+        out.extend_from_slice(b" ago");
+    }
+    out
+}
+
+/// Make text safe inside a single-backtick code span: backticks become `'`
+/// and line breaks become spaces, so the span can't be closed early
+fn escape_code_span(env: &Env, text: &Bytes) -> Bytes {
+    let mut out = Bytes::new(env);
+    for c in text.iter() {
+        out.push_back(match c {
+            b'`' => b'\'',
+            b'\n' | b'\r' => b' ',
+            _ => c,
+        });
+    }
+    out
+}
+
+/// Case-insensitive (ASCII) substring test over raw bytes
+fn contains_ignore_case(haystack: &Bytes, needle: &Bytes) -> bool {
+    let h_len = haystack.len();
+    let n_len = needle.len();
+    if n_len == 0 {
+        return true;
+    }
+    if n_len > h_len {
+        return false;
+    }
+    for start in 0..=(h_len - n_len) {
+        let mut matches = true;
+        for j in 0..n_len {
+            let h = haystack.get(start + j).unwrap().to_ascii_lowercase();
+            let n = needle.get(j).unwrap().to_ascii_lowercase();
+            if h != n {
+                matches = false;
+                break;
+            }
+        }
+        if matches {
+            return true;
+        }
+    }
+    false
+}
+
+/// Route of the task list for a completion filter
+fn list_base(filter: Option<bool>) -> &'static str {
+    match filter {
+        Some(false) => "/tasks/pending",
+        Some(true) => "/tasks/completed",
+        None => "/tasks",
+    }
+}
+
+/// Position of task `id` among the tasks in id order, which is its row
+/// index in the stored task rows
+fn row_index(tasks: &Map<u32, Task>, id: u32) -> u32 {
+    tasks.keys().iter().take_while(|k| *k < id).count() as u32
+}
+
+/// Collect the tasks matching `filter` and the decoded `search` text,
+/// ordered by `sort`
+fn sorted_tasks(
+    env: &Env,
+    tasks: &Map<u32, Task>,
+    filter: Option<bool>,
+    search: Option<&Bytes>,
+    sort: SortOrder,
+) -> Vec<Task> {
+    let mut matching: Vec<Task> = Vec::new(env);
+    for (_, task) in tasks.iter() {
+        if let Some(completed_filter) = filter {
+            if task.completed != completed_filter {
+                continue;
+            }
+        }
+        if let Some(needle) = search {
+            if !contains_ignore_case(&string_to_bytes(env, &task.description), needle) {
+                continue;
+            }
+        }
+        matching.push_back(task);
+    }
+    sort_by(matching, |a, b| sort.compare(a, b))
+}
+
+/// Build a `[label](render:/tasks?sort=...)` link that re-sorts from page one
+fn sort_link(env: &Env, label: &str, base: &str, sort: SortOrder) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"[");
+    out.extend_from_slice(label.as_bytes());
+    out.extend_from_slice(b"](render:");
+    out.extend_from_slice(base.as_bytes());
+    out.extend_from_slice(b"?sort=");
+    out.extend_from_slice(sort.as_str().as_bytes());
+    out.extend_from_slice(b")");
+    out
+}
+
+/// Build a `[label](render:/tasks?page=N)` link, carrying `per_page`,
+/// `sort` and `q` when they are not the defaults
+fn page_link(env: &Env, label: &str, base: &str, page: u32, list: &ListQuery) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"[");
+    out.extend_from_slice(label.as_bytes());
+    out.extend_from_slice(b"](render:");
+    out.extend_from_slice(base.as_bytes());
+    out.extend_from_slice(b"?page=");
+    out.append(&u32_to_bytes(env, page));
+    if list.per_page != DEFAULT_PER_PAGE {
+        out.extend_from_slice(b"&per_page=");
+        out.append(&u32_to_bytes(env, list.per_page));
+    }
+    if list.sort != SortOrder::Id {
+        out.extend_from_slice(b"&sort=");
+        out.extend_from_slice(list.sort.as_str().as_bytes());
+    }
+    if let Some(ref search) = list.search {
+        // Re-encoded, so a raw `)` or space from the query can't end the link
+        out.extend_from_slice(b"&q=");
+        out.append(&url_encode(env, &url_decode(env, search)));
+    }
+    out.extend_from_slice(b")");
+    out
+}
+
 #[contractimpl]
 impl TodoContract {
-    /// Initialize is no longer needed - storage is created lazily per-user
-    pub fn init(_env: Env) {
-        // No-op for backwards compatibility
+    /// Set the admin who may change contract settings. Task storage is
+    /// created lazily per-user, so this is only needed to use `set_theme`.
+    pub fn init(env: Env, admin: Address) {
+        let storage = env.storage().persistent();
+        if storage.has(&DataKey::Admin) {
+            panic!("Already initialized");
+        }
+        storage.set(&DataKey::Admin, &admin);
+    }
+
+    /// Point header/footer includes at a redeployed theme contract (admin only)
+    pub fn set_theme(env: Env, theme: Address) {
+        let admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Admin)
+            .expect("Not initialized");
+        admin.require_auth();
+        env.storage().persistent().set(&DataKey::ThemeContract, &theme);
     }
 
     /// Todo-specific styles that augment the theme
@@ -49,22 +513,25 @@ impl TodoContract {
             .rule(".task-actions", "display: flex; gap: 0.25rem;")
             .rule(".task-actions a", "padding: 0.25rem 0.5rem; border: 1px solid var(--border); border-radius: 3px; font-size: 0.875rem;")
             .rule(".task-actions a:hover", "background: var(--bg-muted);")
+            .rule(".empty", "color: var(--text-muted); font-style: italic; text-align: center; padding: 1rem;")
             .rule(".add-task-form", "margin-bottom: 1.5rem; padding: 1rem; background: var(--bg-muted); border-radius: 8px;")
             .build()
     }
 
-    pub fn add_task(env: Env, description: String, caller: Address) -> u32 {
+    /// Add a task. `caller` stays last, where the viewer appends it for forms.
+    pub fn add_task(
+        env: Env,
+        description: String,
+        due: Option<u64>,
+        priority: u32,
+        caller: Address,
+    ) -> u32 {
         caller.require_auth();
 
-        let tasks_key = DataKey::Tasks(caller.clone());
         let next_id_key = DataKey::NextId(caller.clone());
         let has_tasks_key = DataKey::HasTasks(caller.clone());
 
-        let mut tasks: Map<u32, Task> = env
-            .storage()
-            .persistent()
-            .get(&tasks_key)
-            .unwrap_or(Map::new(&env));
+        let mut tasks = Self::read_tasks(&env, &caller);
 
         let next_id: u32 = env.storage().persistent().get(&next_id_key).unwrap_or(1);
 
@@ -73,23 +540,19 @@ impl TodoContract {
             description,
             completed: false,
             owner: caller.clone(),
+            due,
+            priority,
         };
 
         tasks.set(next_id, task);
-        env.storage().persistent().set(&tasks_key, &tasks);
+        Self::write_tasks(&env, &caller, &tasks);
+        Self::write_task_row(&env, &caller, &tasks, next_id);
         env.storage()
             .persistent()
             .set(&next_id_key, &(next_id + 1));
 
         // Update global stats
-        let total_tasks: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::TotalTasks)
-            .unwrap_or(0);
-        env.storage()
-            .persistent()
-            .set(&DataKey::TotalTasks, &(total_tasks + 1));
+        adjust_count(&env, &DataKey::TotalTasks, 1);
 
         // Track unique users
         let user_has_tasks: bool = env
@@ -99,6 +562,17 @@ impl TodoContract {
             .unwrap_or(false);
         if !user_has_tasks {
             env.storage().persistent().set(&has_tasks_key, &true);
+            let listed: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ListedUsers)
+                .unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&DataKey::User(listed), &caller);
+            env.storage()
+                .persistent()
+                .set(&DataKey::ListedUsers, &(listed + 1));
             let user_count: u32 = env
                 .storage()
                 .persistent()
@@ -115,47 +589,121 @@ impl TodoContract {
     pub fn complete_task(env: Env, id: u32, caller: Address) {
         caller.require_auth();
 
-        let tasks_key = DataKey::Tasks(caller.clone());
-        let mut tasks: Map<u32, Task> = env
-            .storage()
-            .persistent()
-            .get(&tasks_key)
-            .unwrap_or(Map::new(&env));
+        let mut tasks = Self::read_tasks(&env, &caller);
 
         if let Some(mut task) = tasks.get(id) {
+            if !task.completed {
+                adjust_count(&env, &DataKey::CompletedTasks, 1);
+            }
             task.completed = true;
             tasks.set(id, task);
-            env.storage().persistent().set(&tasks_key, &tasks);
+            Self::write_tasks(&env, &caller, &tasks);
+            Self::write_task_row(&env, &caller, &tasks, id);
+        }
+    }
+
+    /// Flip a task between completed and pending, so a mistaken "Done" can
+    /// be undone
+    pub fn toggle_task(env: Env, id: u32, caller: Address) {
+        caller.require_auth();
+
+        let mut tasks = Self::read_tasks(&env, &caller);
+
+        if let Some(mut task) = tasks.get(id) {
+            task.completed = !task.completed;
+            adjust_count(&env, &DataKey::CompletedTasks, if task.completed { 1 } else { -1 });
+            tasks.set(id, task);
+            Self::write_tasks(&env, &caller, &tasks);
+            Self::write_task_row(&env, &caller, &tasks, id);
+        }
+    }
+
+    /// Replace a task's description, e.g. to fix a typo
+    pub fn edit_task(env: Env, id: u32, description: String, caller: Address) {
+        caller.require_auth();
+
+        let mut tasks = Self::read_tasks(&env, &caller);
+
+        if let Some(mut task) = tasks.get(id) {
+            task.description = description;
+            tasks.set(id, task);
+            Self::write_tasks(&env, &caller, &tasks);
+            Self::write_task_row(&env, &caller, &tasks, id);
         }
     }
 
     pub fn delete_task(env: Env, id: u32, caller: Address) {
         caller.require_auth();
 
-        let tasks_key = DataKey::Tasks(caller.clone());
-        let mut tasks: Map<u32, Task> = env
-            .storage()
-            .persistent()
-            .get(&tasks_key)
-            .unwrap_or(Map::new(&env));
+        let mut tasks = Self::read_tasks(&env, &caller);
 
         // Only decrement if task exists
-        if tasks.get(id).is_some() {
+        if let Some(task) = tasks.get(id) {
             tasks.remove(id);
-            env.storage().persistent().set(&tasks_key, &tasks);
+            Self::write_tasks(&env, &caller, &tasks);
+            Self::write_task_rows(&env, &caller, &tasks, row_index(&tasks, id));
 
-            // Decrement global task count
-            let total_tasks: u32 = env
-                .storage()
-                .persistent()
-                .get(&DataKey::TotalTasks)
-                .unwrap_or(0);
-            if total_tasks > 0 {
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::TotalTasks, &(total_tasks - 1));
+            // Decrement global counts, including completions if it was done
+            adjust_count(&env, &DataKey::TotalTasks, -1);
+            if task.completed {
+                adjust_count(&env, &DataKey::CompletedTasks, -1);
+            }
+        }
+    }
+
+    /// Up to `limit` (at most 50) users who have added a task, from
+    /// position `start` in order of their first task. Users from before this
+    /// list was kept are only counted in `UserCount`.
+    pub fn get_users(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let storage = env.storage().persistent();
+        let listed: u32 = storage.get(&DataKey::ListedUsers).unwrap_or(0);
+        let end = start.saturating_add(limit.min(MAX_PER_PAGE)).min(listed);
+        let mut users = Vec::new(&env);
+        for i in start..end {
+            if let Some(user) = storage.get(&DataKey::User(i)) {
+                users.push_back(user);
             }
         }
+        users
+    }
+
+    /// Remove all of the caller's completed tasks in one call
+    /// Record that `caller` has read the notice that task lists are public,
+    /// so the task list stops showing it
+    pub fn acknowledge_notice(env: Env, caller: Address) {
+        caller.require_auth();
+        env.storage().persistent().set(&DataKey::NoticeSeen(caller), &true);
+    }
+
+    /// Whether `user` has acknowledged the public-data notice
+    pub fn notice_seen(env: Env, user: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::NoticeSeen(user))
+            .unwrap_or(false)
+    }
+
+    pub fn clear_completed(env: Env, caller: Address) {
+        caller.require_auth();
+
+        let tasks = Self::read_tasks(&env, &caller);
+        let mut kept: Map<u32, Task> = Map::new(&env);
+        let mut first_cleared = None;
+        for (id, task) in tasks.iter() {
+            if !task.completed {
+                kept.set(id, task);
+            } else if first_cleared.is_none() {
+                first_cleared = Some(row_index(&tasks, id));
+            }
+        }
+
+        if let Some(first_cleared) = first_cleared {
+            let cleared = (tasks.len() - kept.len()) as i32;
+            Self::write_tasks(&env, &caller, &kept);
+            Self::write_task_rows(&env, &caller, &kept, first_cleared);
+            adjust_count(&env, &DataKey::TotalTasks, -cleared);
+            adjust_count(&env, &DataKey::CompletedTasks, -cleared);
+        }
     }
 
     /// Get global stats
@@ -173,91 +721,296 @@ impl TodoContract {
         (total_tasks, user_count)
     }
 
-    /// Get tasks for a specific user
-    pub fn get_tasks(env: Env, user: Address) -> Vec<Task> {
-        let tasks_key = DataKey::Tasks(user);
-        let tasks: Map<u32, Task> = env
-            .storage()
+    /// Get the number of completed tasks across all users
+    pub fn get_completed_count(env: Env) -> u32 {
+        env.storage()
             .persistent()
-            .get(&tasks_key)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::CompletedTasks)
+            .unwrap_or(0)
+    }
 
-        let mut result: Vec<Task> = Vec::new(&env);
-        for (_, task) in tasks.iter() {
-            result.push_back(task);
-        }
-        result
+    /// Get tasks for a specific user, in ascending id order
+    pub fn get_tasks(env: Env, user: Address) -> Vec<Task> {
+        let tasks = Self::read_tasks(&env, &user);
+        sorted_tasks(&env, &tasks, None, None, SortOrder::Id)
     }
 
     /// Get a specific task for a user
     pub fn get_task(env: Env, id: u32, user: Address) -> Option<Task> {
-        let tasks_key = DataKey::Tasks(user);
-        let tasks: Map<u32, Task> = env
-            .storage()
-            .persistent()
-            .get(&tasks_key)
-            .unwrap_or(Map::new(&env));
+        let tasks = Self::read_tasks(&env, &user);
 
         tasks.get(id)
     }
 
+    /// Get a user's tasks whose description contains `query`, ignoring ASCII case
+    pub fn search_tasks(env: Env, user: Address, query: String) -> Vec<Task> {
+        let tasks = Self::read_tasks(&env, &user);
+        let needle = string_to_bytes(&env, &query);
+        sorted_tasks(&env, &tasks, None, Some(&needle), SortOrder::Id)
+    }
+
     pub fn render(env: Env, path: Option<String>, viewer: Option<Address>) -> Bytes {
-        // Get tasks for the viewer (if connected)
-        let tasks: Map<u32, Task> = if let Some(ref user) = viewer {
-            let tasks_key = DataKey::Tasks(user.clone());
-            env.storage()
-                .persistent()
-                .get(&tasks_key)
-                .unwrap_or(Map::new(&env))
+        let viewer = Viewer::new(viewer);
+        let tasks = Self::load_tasks(&env, viewer.address());
+
+        let wallet_connected = viewer.is_connected();
+
+        // Pending count shown as a badge on the Tasks nav link
+        let mut pending = 0u32;
+        for (_, task) in tasks.iter() {
+            if !task.completed {
+                pending += 1;
+            }
+        }
+
+        // Route on the path alone; the query string carries page parameters
+        let (route, query) = split_query(&env, &path_to_bytes(&env, &path));
+        let list = ListQuery::parse(&query);
+
+        let (format, route) = RenderFormat::from_path(&env, &route);
+        if format == Format::Json {
+            if let Some(id) = parse_id(&route, b"/task/") {
+                return Self::render_json_task(&env, &tasks, id);
+            }
+            let filter = list_filter(&route);
+            if filter.is_none() && !path_eq(&route, b"/") && !path_eq(&route, b"/tasks") {
+                return Self::render_json_404(&env);
+            }
+            return Self::render_json(&env, &tasks, filter, wallet_connected);
+        }
+
+        // An empty path is the home page, not a missing one
+        let route = if route.is_empty() {
+            Bytes::from_slice(&env, b"/")
         } else {
-            Map::new(&env)
+            route
         };
 
-        let wallet_connected = viewer.is_some();
+        // Routes set the nav's `title` slot; it is resolved once the page is built
+        let mut slots = Slots::new(&env);
 
         // Use the Router for clean path matching
-        Router::new(&env, path)
-            .handle(b"/", |_| Self::render_home(&env, wallet_connected))
-            .or_handle(b"/about", |_| Self::render_about(&env))
+        let output = Router::from_bytes(&env, route.clone())
+            .handle(b"/", |_| Self::render_home(&env, pending, wallet_connected))
+            .or_handle(b"/about", |_| {
+                Self::render_about(&env, pending, tasks.len() - pending)
+            })
             .or_handle(b"/tasks", |_| {
-                Self::render_task_list(&env, &tasks, None, wallet_connected)
+                Self::render_task_list(&env, &tasks, None, &list, pending, &viewer)
             })
             .or_handle(b"/tasks/pending", |_| {
-                Self::render_task_list(&env, &tasks, Some(false), wallet_connected)
+                Self::render_task_list(&env, &tasks, Some(false), &list, pending, &viewer)
             })
             .or_handle(b"/pending", |_| {
-                Self::render_task_list(&env, &tasks, Some(false), wallet_connected)
+                Self::render_task_list(&env, &tasks, Some(false), &list, pending, &viewer)
             })
             .or_handle(b"/tasks/completed", |_| {
-                Self::render_task_list(&env, &tasks, Some(true), wallet_connected)
+                Self::render_task_list(&env, &tasks, Some(true), &list, pending, &viewer)
             })
             .or_handle(b"/completed", |_| {
-                Self::render_task_list(&env, &tasks, Some(true), wallet_connected)
+                Self::render_task_list(&env, &tasks, Some(true), &list, pending, &viewer)
             })
-            .or_handle(b"/task/{id}", |req| {
+            .or_handle(b"/leaderboard", |_| Self::render_leaderboard(&env, pending))
+            .or_handle(b"/_sitemap", |_| Self::render_sitemap(&env, pending))
+            .or_handle(b"/task/{id}/edit", |req| {
                 let id = req.get_var_u32(b"id").unwrap_or(0);
-                Self::render_single_task(&env, &tasks, id)
+                slots.set("title", task_title(&env, "Edit Task", id));
+                Self::render_edit_task(&env, &tasks, id, pending)
             })
-            .or_handle(b"/json", |_| {
-                Self::render_json(&env, &tasks, None, wallet_connected)
+            .or_handle(b"/task/{id}/delete", |req| {
+                let id = req.get_var_u32(b"id").unwrap_or(0);
+                slots.set("title", task_title(&env, "Delete Task", id));
+                Self::render_delete_confirm(&env, id, pending)
             })
-            .or_handle(b"/json/*", |req| {
-                Self::render_json(&env, &tasks, req.get_wildcard(), wallet_connected)
+            .or_handle(b"/task/{id}", |req| {
+                let id = req.get_var_u32(b"id").unwrap_or(0);
+                slots.set("title", task_title(&env, "Task", id));
+                Self::render_single_task(&env, &tasks, id, pending)
             })
-            .or_default(|_| Self::render_home(&env, wallet_connected))
+            .or_default(|_| Self::render_404(&env, &route, pending));
+
+        slots.resolve(&output)
     }
 
-    fn render_home(env: &Env, wallet_connected: bool) -> Bytes {
-        let mut md = MarkdownBuilder::new(env)
-            .include(THEME_CONTRACT_ID, "header")
+    /// Get a single chunk of stored task rows (called by the viewer for
+    /// progressive loading)
+    pub fn get_chunk(env: Env, collection: Symbol, index: u32) -> Option<Bytes> {
+        Chonk::open(&env, collection).get(index)
+    }
+
+    /// SHA-256 of `render(path, viewer)`, so a viewer can keep its cached
+    /// output while the tag is unchanged. This renders the full page, so it
+    /// only pays off by returning 32 bytes instead of the output; keep it a
+    /// plain hash and never add work here that `render` doesn't already do.
+    pub fn render_etag(env: Env, path: Option<String>, viewer: Option<Address>) -> BytesN<32> {
+        let output = Self::render(env.clone(), path, viewer);
+        env.crypto().sha256(&output).to_bytes()
+    }
+
+    /// Debugging aid: a ```` ```diff ```` block comparing the renders of
+    /// `old_path` and `new_path` for the same viewer, for checking what a
+    /// route or query change does to the output
+    pub fn render_diff(
+        env: Env,
+        old_path: Option<String>,
+        new_path: Option<String>,
+        viewer: Option<Address>,
+    ) -> Bytes {
+        let old = Self::render(env.clone(), old_path, viewer.clone());
+        let new = Self::render(env.clone(), new_path, viewer);
+        diff_render(&env, &old, &new)
+    }
+
+    /// Render one named fragment of a page without the page shell, so a viewer
+    /// can refresh a single section in place. Supported fragments: `tasks`.
+    /// Unknown fragments render as empty.
+    pub fn render_fragment(
+        env: Env,
+        path: Option<String>,
+        viewer: Option<Address>,
+        fragment: Symbol,
+    ) -> Bytes {
+        if fragment == symbol_short!("tasks") && viewer.is_some() {
+            let tasks = Self::load_tasks(&env, viewer.as_ref());
+            let (route, query) = split_query(&env, &path_to_bytes(&env, &path));
+            let list = ListQuery::parse(&query);
+            return Self::render_task_items(&env, &tasks, list_filter(&route), &list);
+        }
+        Bytes::new(&env)
+    }
+
+    /// Get tasks for the viewer (if connected)
+    fn load_tasks(env: &Env, viewer: Option<&Address>) -> Map<u32, Task> {
+        if let Some(user) = viewer {
+            Self::read_tasks(env, user)
+        } else {
+            Map::new(env)
+        }
+    }
+
+    /// Read a user's tasks, upgrading legacy entries with no due date and
+    /// no priority
+    fn read_tasks(env: &Env, user: &Address) -> Map<u32, Task> {
+        let storage = env.storage().persistent();
+        if let Some(tasks) = storage.get(&DataKey::TasksV2(user.clone())) {
+            return tasks;
+        }
+
+        let legacy: Map<u32, LegacyTask> = storage
+            .get(&DataKey::Tasks(user.clone()))
+            .unwrap_or(Map::new(env));
+        let mut tasks: Map<u32, Task> = Map::new(env);
+        for (id, task) in legacy.iter() {
+            tasks.set(
+                id,
+                Task {
+                    id: task.id,
+                    description: task.description,
+                    completed: task.completed,
+                    owner: task.owner,
+                    due: None,
+                    priority: 0,
+                },
+            );
+        }
+        tasks
+    }
+
+    /// Store a user's tasks in the current layout, dropping any legacy copy
+    fn write_tasks(env: &Env, user: &Address, tasks: &Map<u32, Task>) {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::TasksV2(user.clone()), tasks);
+        storage.remove(&DataKey::Tasks(user.clone()));
+        Self::update_leaderboard(env, user, tasks);
+    }
+
+    /// Move `user` to their place on the leaderboard for their current task
+    /// counts. Only the top `LEADERBOARD_SIZE` are kept, so a user who drops
+    /// off returns on their next write once they rank again.
+    fn update_leaderboard(env: &Env, user: &Address, tasks: &Map<u32, Task>) {
+        let storage = env.storage().persistent();
+        let mut board: Vec<LeaderboardEntry> =
+            storage.get(&DataKey::Leaderboard).unwrap_or(Vec::new(env));
+        if let Some(pos) = board.iter().position(|entry| entry.user == *user) {
+            board.remove(pos as u32);
+        }
+
+        let total = tasks.len();
+        let completed = tasks.values().iter().filter(|task| task.completed).count() as u32;
+        if total > 0 {
+            board.push_back(LeaderboardEntry {
+                user: user.clone(),
+                tasks: total,
+                completed,
+            });
+        }
+        // Most tasks first; the sort is stable, so ties keep the earlier entry ahead
+        let mut board = sort_by(board, |a, b| b.tasks.cmp(&a.tasks));
+        while board.len() > LEADERBOARD_SIZE {
+            board.pop_back();
+        }
+        storage.set(&DataKey::Leaderboard, &board);
+    }
+
+    /// Collection holding a user's rendered task rows, one chunk per task in
+    /// id order, for the task list's continuation marker. Render calls can't
+    /// write, so rows are rendered when tasks change, and hold nothing that
+    /// depends on the time.
+    fn task_rows(env: &Env, user: &Address) -> Symbol {
+        let name = task_rows_name(env, user);
+        Symbol::new(env, core::str::from_utf8(&name).unwrap())
+    }
+
+    /// Store the row for task `id` after it was added or changed. Rows that
+    /// are out of step with the tasks, such as a legacy list's, are rewritten
+    /// in full.
+    fn write_task_row(env: &Env, user: &Address, tasks: &Map<u32, Task>, id: u32) {
+        let rows = Chonk::open(env, Self::task_rows(env, user));
+        let index = row_index(tasks, id);
+        let row = Self::render_task_row(env, &tasks.get(id).unwrap());
+        if rows.count() == tasks.len() && index < rows.count() {
+            rows.set(index, row);
+        } else if rows.count() + 1 == tasks.len() && index == rows.count() {
+            rows.push(row);
+        } else {
+            Self::write_task_rows(env, user, tasks, 0);
+        }
+    }
+
+    /// Rewrite the rows from index `from` on and drop any left past the end,
+    /// after tasks were removed from that position.
+    fn write_task_rows(env: &Env, user: &Address, tasks: &Map<u32, Task>, from: u32) {
+        let rows = Chonk::open(env, Self::task_rows(env, user));
+        let from = if from > rows.count() { 0 } else { from };
+        for (i, task) in tasks.values().iter().enumerate().skip(from as usize) {
+            let row = Self::render_task_row(env, &task);
+            if (i as u32) < rows.count() {
+                rows.set(i as u32, row);
+            } else {
+                rows.push(row);
+            }
+        }
+        while rows.count() > tasks.len() {
+            rows.remove(rows.count() - 1);
+        }
+    }
+
+    /// Shared navigation line with a pending-count badge on Tasks
+    fn render_nav(env: &Env, pending: u32) -> Bytes {
+        MarkdownBuilder::new(env)
             .render_link("Home", "/")
             .text(" | ")
-            .render_link("Tasks", "/tasks")
+            .render_link_badge("Tasks", "/tasks", pending)
             .text(" | ")
             .render_link("About", "/about")
+            .slot("title")
             .newline()
             .newline()
-            .hr()
+            .build()
+    }
+
+    fn render_home(env: &Env, pending: u32, wallet_connected: bool) -> Bytes {
+        let mut md = MarkdownBuilder::new(env)
             .h2("Welcome to the Soroban Render Demo")
             .paragraph(
                 "This is a **fully functional todo application** where the entire user interface is defined by the smart contract itself.",
@@ -270,7 +1023,7 @@ impl TodoContract {
                 "**Self-contained UI**: The contract's `render()` function returns this markdown you're reading",
             )
             .list_item("**Interactive elements**: Forms and buttons trigger real blockchain transactions")
-            .list_item("**Per-user storage**: Each wallet has its own private task list")
+            .list_item("**Per-user storage**: Each wallet has its own task list")
             .list_item(
                 "**Composability**: This app includes header/footer components from a separate theme contract",
             )
@@ -287,156 +1040,429 @@ impl TodoContract {
                     "Connect your wallet (button in top-right) to create and manage your personal todo list.",
                 )
                 .h3("Get Started")
-                .paragraph("Each user has their own private task list stored on the blockchain.");
+                .paragraph("Each user has their own task list stored on the blockchain.");
         }
 
-        md.include(THEME_CONTRACT_ID, "footer").build()
+        page(
+            env,
+            &theme_contract(env),
+            "header",
+            Self::render_nav(env, pending),
+            md.build(),
+            "footer",
+        )
     }
 
-    fn render_about(env: &Env) -> Bytes {
-        // Get stats
-        let total_tasks: u32 = env
+    /// The users with the most tasks, with completed counts, as kept by
+    /// `update_leaderboard`
+    fn render_leaderboard(env: &Env, pending: u32) -> Bytes {
+        let ranked: Vec<LeaderboardEntry> = env
             .storage()
             .persistent()
-            .get(&DataKey::TotalTasks)
-            .unwrap_or(0);
-        let user_count: u32 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::UserCount)
-            .unwrap_or(0);
+            .get(&DataKey::Leaderboard)
+            .unwrap_or(Vec::new(env));
 
-        MarkdownBuilder::new(env)
-            .include(THEME_CONTRACT_ID, "header")
-            .render_link("Home", "/")
-            .text(" | ")
-            .render_link("Tasks", "/tasks")
-            .text(" | ")
-            .render_link("About", "/about")
-            .newline()
-            .newline()
-            .hr()
-            .h2("About Soroban Render")
+        let mut md = MarkdownBuilder::new(env).h1("Leaderboard");
+        if ranked.is_empty() {
+            md = md.empty_state("No users yet.");
+        } else {
+            md = md.raw_str("| Rank | User | Tasks | Completed |\n|---|---|---|---|\n");
+            for (i, entry) in ranked.iter().enumerate() {
+                md = md
+                    .raw_str("| ")
+                    .number(i as u32 + 1)
+                    .raw_str(" | `")
+                    .raw(address_short(env, &entry.user))
+                    .raw_str("` | ")
+                    .number(entry.tasks)
+                    .raw_str(" | ")
+                    .number(entry.completed)
+                    .raw_str(" |\n");
+            }
+        }
+        page(
+            env,
+            &theme_contract(env),
+            "header",
+            Self::render_nav(env, pending),
+            md.build(),
+            "footer",
+        )
+    }
+
+    /// "Page not found" for a route nothing handles, echoing the escaped
+    /// path and linking home
+    fn render_404(env: &Env, route: &Bytes, pending: u32) -> Bytes {
+        let body = MarkdownBuilder::new(env)
+            .h1("Page Not Found")
+            .raw_str("Page not found: `")
+            .raw(escape_code_span(env, route))
+            .raw_str("`")
+            .newline()
+            .newline()
+            .render_link("Back to Home", "/")
+            .newline()
+            .build();
+        page(env, &theme_contract(env), "header", Self::render_nav(env, pending), body, "footer")
+    }
+
+    /// List every declared route so viewers and crawlers can index the app.
+    /// Parameterized routes are shown as patterns rather than links.
+    fn render_sitemap(env: &Env, pending: u32) -> Bytes {
+        let mut md = MarkdownBuilder::new(env).h1("Sitemap");
+        for (path, title) in ROUTES {
+            md = md.raw_str("- ");
+            md = if path.contains('{') {
+                md.text(title)
+            } else {
+                md.render_link(title, path)
+            };
+            md = md.raw_str(" - `").raw_str(path).raw_str("`").newline();
+        }
+        page(
+            env,
+            &theme_contract(env),
+            "header",
+            Self::render_nav(env, pending),
+            md.build(),
+            "footer",
+        )
+    }
+
+    fn render_about(env: &Env, pending: u32, completed: u32) -> Bytes {
+        // Get stats
+        let total_tasks: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TotalTasks)
+            .unwrap_or(0);
+        let user_count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserCount)
+            .unwrap_or(0);
+        let completed_tasks = Self::get_completed_count(env.clone());
+
+        // Links are written as references, with the URLs listed once at the end
+        let mut md = MarkdownBuilder::new(env)
+            .h2("About Soroban Render")
             .paragraph(
                 "Soroban Render is a community convention for building **self-contained, renderable dApps** on Stellar's Soroban smart contract platform.",
             )
-            .info(
-                "Inspired by [Gno.land's Render() function](https://docs.gno.land/users/explore-with-gnoweb/#viewing-rendered-content), Soroban Render allows smart contracts to define their own user interface.",
+            .raw_str("> [!INFO]\n> Inspired by ")
+            .with_link_refs(env)
+            .link(
+                "Gno.land's Render() function",
+                "https://docs.gno.land/users/explore-with-gnoweb/#viewing-rendered-content",
             )
-            .h3("Live Stats")
-            .columns_start()
-            .raw_str("**Total Tasks**\n\n# ")
-            .number(total_tasks)
-            .raw_str("\n\ntasks stored on-chain\n")
-            .column_separator()
-            .raw_str("**Unique Users**\n\n# ")
-            .number(user_count)
-            .raw_str("\n\nwallets with tasks\n")
-            .columns_end()
-            .h3("How It Works")
-            .columns_start()
-            .raw_str("**1. Contract Renders UI**\n\nThe `render(path, viewer)` function returns markdown or JSON describing the interface.\n")
-            .column_separator()
-            .raw_str("**2. Special Protocols**\n\n`render:` for navigation, `tx:` for transactions, `form:` for form submissions.\n")
-            .column_separator()
-            .raw_str("**3. Universal Viewer**\n\nAny contract implementing `render()` can be viewed with the same generic viewer.\n")
-            .columns_end()
-            .h3("Learn More")
-            .list_item("[View the source code on GitHub](https://github.com/wyhaines/soroban-render)")
-            .list_item("[Soroban Documentation](https://soroban.stellar.org/docs)")
-            .list_item("[Stellar Developer Portal](https://developers.stellar.org)")
-            .newline()
-            .include(THEME_CONTRACT_ID, "footer")
-            .build()
+            .with(|md| {
+                md.raw_str(", Soroban Render allows smart contracts to define their own user interface.\n\n")
+                    .h3("Live Stats")
+                    .columns_start()
+                    .raw_str("**Total Tasks**\n\n# ")
+                    .raw(u32_to_bytes_grouped(env, total_tasks, b','))
+                    .raw_str("\n\ntasks stored on-chain\n")
+                    .column_separator()
+                    .raw_str("**Unique Users**\n\n# ")
+                    .raw(u32_to_bytes_grouped(env, user_count, b','))
+                    .raw_str("\n\nwallets with tasks\n")
+                    .column_separator()
+                    .raw_str("**Completed**\n\n# ")
+                    .raw(percent_to_bytes(env, completed_tasks, total_tasks, 0))
+                    .raw_str("\n\nof all tasks done\n")
+                    .columns_end()
+            });
+
+        // The viewer's own completion, when they have tasks
+        let own_total = pending + completed;
+        if own_total > 0 {
+            md = md.with(|md| {
+                md.h3("Your Progress")
+                    .raw(progress_bar(env, completed, own_total))
+                    .newline()
+                    .newline()
+                    .raw_str("**")
+                    .raw(percent_to_bytes(env, completed, own_total, 0))
+                    .raw_str(" complete** (")
+                    .number(completed)
+                    .text(" of ")
+                    .number(own_total)
+                    .text(" tasks)")
+                    .newline()
+                    .newline()
+            });
+        }
+
+        let body = md
+            .with(|md| {
+                md.h3("How It Works")
+                    .columns_start()
+                    .raw_str("**1. Contract Renders UI**\n\nThe `render(path, viewer)` function returns markdown or JSON describing the interface.\n")
+                    .column_separator()
+                    .raw_str("**2. Special Protocols**\n\n`render:` for navigation, `tx:` for transactions, `form:` for form submissions.\n")
+                    .column_separator()
+                    .raw_str("**3. Universal Viewer**\n\nAny contract implementing `render()` can be viewed with the same generic viewer.\n")
+                    .columns_end()
+                    .h3("Embedding")
+                    .paragraph("Other contracts can show this app's header by including it, with an optional title:")
+                    .raw_str("`{{noparse}}")
+                    .raw(Include::this(env).func("header").args("{\"title\":\"Shared list\"}").build())
+                    .raw_str("{{/noparse}}`\n\n")
+                    .h3("Learn More")
+                    .raw_str("- ")
+            })
+            .link("View the source code on GitHub", REPO_URL)
+            .with(|md| md.raw_str("\n- "))
+            .link("Soroban Documentation", "https://soroban.stellar.org/docs")
+            .with(|md| md.raw_str("\n- "))
+            .link("Stellar Developer Portal", "https://developers.stellar.org")
+            .with(|md| md.newline())
+            .build();
+
+        page(env, &theme_contract(env), "header", Self::render_nav(env, pending), body, "footer")
     }
 
     fn render_task_list(
         env: &Env,
         tasks: &Map<u32, Task>,
         filter: Option<bool>,
-        wallet_connected: bool,
+        list: &ListQuery,
+        pending: u32,
+        viewer: &Viewer,
     ) -> Bytes {
-        let mut md = MarkdownBuilder::new(env)
-            .include(THEME_CONTRACT_ID, "header")
-            .render_link("Home", "/")
+        let nav = Self::render_nav(env, pending);
+        let mut md = viewer.require_connected(
+            MarkdownBuilder::new(env),
+            "**Please connect your wallet** to view and manage your personal todo list. Each user has their own task list that only they can modify.",
+        );
+        let Some(user) = viewer.address() else {
+            return page(env, &theme_contract(env), "header", nav, md.build(), "footer");
+        };
+
+        if !Self::notice_seen(env.clone(), user.clone()) {
+            md = md
+                .raw_str("> [!NOTE]\n> Tasks are stored on a public ledger: only you can change your list, but anyone can read it. ")
+                .acknowledge_button("Got it", "acknowledge_notice")
+                .newline()
+                .newline();
+        }
+
+        if let Some(ref message) = list.message {
+            md = md.toast(message, list_base(filter));
+        }
+
+        // Add task form, returning to this list with a confirmation
+        let mut redirect = Bytes::from_slice(env, list_base(filter).as_bytes());
+        redirect.extend_from_slice(b"?msg=Task+added");
+        md = md
+            .h2("Add Task")
+            .textarea("description", 2, "What needs to be done?")
+            .input("due", "Due (ledger timestamp)")
+            .hidden("_redirect", &redirect)
+            .select("priority", &PRIORITY_OPTIONS)
+            .form_link("Add Task", "add_task")
+            .text(" ")
+            .shortcut_hint("Add task", "N")
+            .newline()
+            .newline()
+            .h2("Filter")
+            .raw(NavBuilder::new(&filter_nav_items(filter)).markdown(env))
+            .newline()
+            .newline()
+            .text("Sort: ")
+            .raw(sort_link(env, "Oldest first", list_base(filter), SortOrder::Id))
             .text(" | ")
-            .render_link("Tasks", "/tasks")
+            .raw(sort_link(env, "Newest first", list_base(filter), SortOrder::IdDesc))
             .text(" | ")
-            .render_link("About", "/about")
+            .raw(sort_link(env, "Pending first", list_base(filter), SortOrder::Status))
+            .text(" | ")
+            .raw(sort_link(env, "Priority", list_base(filter), SortOrder::Priority))
             .newline()
             .newline()
-            .hr();
+            .h2("Your Tasks");
 
-        if !wallet_connected {
-            md = md
-                .h2("Connect Your Wallet")
-                .paragraph(
-                    "**Please connect your wallet** to view and manage your personal todo list.",
-                )
-                .paragraph(
-                    "Each user has their own private task list that only they can see and modify.",
-                );
-        } else {
-            // Add task form
+        if let Some(ref q) = list.search {
             md = md
-                .h2("Add Task")
-                .textarea("description", 2, "What needs to be done?")
-                .form_link("Add Task", "add_task")
-                .h2("Filter")
-                .render_link("All", "/tasks")
-                .text(" | ")
-                .render_link("Pending", "/tasks/pending")
-                .text(" | ")
-                .render_link("Completed", "/tasks/completed")
-                .newline()
+                .raw_str("Showing tasks matching **")
+                .raw(escape_markdown(env, &url_decode(env, q)))
+                .raw_str("** ")
+                .render_link("Clear search", list_base(filter))
                 .newline()
-                .h2("Your Tasks");
+                .newline();
+        }
 
-            let mut has_tasks = false;
-            for (_, task) in tasks.iter() {
-                // Apply filter
-                if let Some(completed_filter) = filter {
-                    if task.completed != completed_filter {
-                        continue;
-                    }
-                }
+        md = md
+            .raw(Self::render_task_items(env, tasks, filter, list))
+            .legend(&vec![
+                env,
+                (Bytes::from_slice(env, b"`high`"), Bytes::from_slice(env, b"priority")),
+                (Bytes::from_slice(env, "⚠".as_bytes()), Bytes::from_slice(env, b"past due")),
+            ]);
 
-                has_tasks = true;
+        if tasks.len() > pending {
+            md = md
+                .newline()
+                .checked_tx_link("Clear completed", "clear_completed", "{}")
+                .newline();
+        }
 
-                // Use checkbox pattern
-                md = md.checkbox(task.completed, "");
+        page(env, &theme_contract(env), "header", nav, md.build(), "footer")
+    }
 
-                if task.completed {
-                    md = md
-                        .raw_str("~~")
-                        .text_string(&task.description)
-                        .raw_str("~~");
-                } else {
-                    md = md.text_string(&task.description);
-                }
+    /// One page of task rows, shared by the task list page and the `tasks`
+    /// fragment, ordered and windowed per `list`.
+    fn render_task_items(
+        env: &Env,
+        tasks: &Map<u32, Task>,
+        filter: Option<bool>,
+        list: &ListQuery,
+    ) -> Bytes {
+        let mut md = MarkdownBuilder::new(env);
+
+        let search = list.search.as_ref().map(|q| url_decode(env, q));
+        let sorted = sorted_tasks(env, tasks, filter, search.as_ref(), list.sort);
+        let total = sorted.len();
+        let start = (list.page - 1).saturating_mul(list.per_page).min(total);
+        let end = start.saturating_add(list.per_page).min(total);
+
+        // Overdue is time-dependent, so it's flagged here rather than in the
+        // rows, which continuation pages load from storage
+        let mut overdue = sorted.iter().filter(|task| is_overdue(env, task)).peekable();
+        if overdue.peek().is_some() {
+            md = md.raw(badge(env, "overdue", BadgeKind::Danger));
+            for (i, task) in overdue.enumerate() {
+                md = md
+                    .text(if i == 0 { " " } else { ", " })
+                    .raw_str("[#")
+                    .number(task.id)
+                    .raw_str("](render:/task/")
+                    .number(task.id)
+                    .raw_str(")");
+            }
+            md = md.newline().newline();
+        }
 
-                md = md.text(" (#").number(task.id).text(") ");
+        if start < end {
+            let sort = Bytes::from_slice(env, list.sort.as_str().as_bytes());
+            md = md.sortable_action_table_header(&TASK_COLUMNS, Some(&sort), list_base(filter));
+        }
+        for i in start..end {
+            md = md.raw(Self::render_task_row(env, &sorted.get(i).unwrap()));
+        }
 
-                // Action buttons
-                if !task.completed {
-                    md = md.tx_link_id("Done", "complete_task", task.id).text(" ");
+        // The unfiltered list in id order continues from the stored rows
+        // rather than paging, so the viewer can load the rest progressively.
+        // Legacy lists have no stored rows until their next write, so they
+        // keep paging.
+        let mut chunked = false;
+        if filter.is_none() && search.is_none() && list.sort == SortOrder::Id && list.page == 1 {
+            if let Some(first) = sorted.get(0) {
+                let name = task_rows_name(env, &first.owner);
+                let name = core::str::from_utf8(&name).unwrap();
+                chunked = Chonk::open(env, Symbol::new(env, name)).count() == total;
+                // Directly below the last row, so the viewer continues the table
+                if chunked && end < total {
+                    md = md.continuation(name, end, Some(total));
                 }
-                md = md.tx_link_id("Delete", "delete_task", task.id).newline();
             }
+        }
+
+        if start < end {
+            md = md.newline();
+        }
 
-            if !has_tasks {
-                if filter.is_some() {
-                    md = md.paragraph("*No matching tasks.*");
+        if total == 0 {
+            let message = empty_tasks_message(filter.is_some() || search.is_some());
+            md = md.empty_state(message);
+        } else if start == end {
+            md = md.empty_state("No tasks on this page.");
+        }
+
+        // Previous / next links when the list spans more than one page
+        let has_prev = list.page > 1 && total > 0;
+        let has_next = end < total && !chunked;
+        if has_prev || has_next {
+            let base = list_base(filter);
+            md = md.newline();
+            if has_prev {
+                let prev = if start == end {
+                    // Past the end: jump back to the last page
+                    total.div_ceil(list.per_page)
                 } else {
-                    md = md.paragraph("*No tasks yet. Add one above!*");
-                }
+                    list.page - 1
+                };
+                md = md.raw(page_link(env, "Previous", base, prev, list));
             }
+            if has_prev && has_next {
+                md = md.text(" | ");
+            }
+            if has_next {
+                md = md.raw(page_link(env, "Next", base, list.page + 1, list));
+            }
+            md = md.newline();
+        }
+
+        md.build()
+    }
+
+    /// One task table row: id, status badge, description, priority, and
+    /// actions. Rows are also stored as chunks, so overdue markers are left
+    /// to the list to render live.
+    fn render_task_row(env: &Env, task: &Task) -> Bytes {
+        let mut id = Bytes::from_slice(env, b"[#");
+        id.append(&u32_to_bytes(env, task.id));
+        id.extend_from_slice(b"](render:/task/");
+        id.append(&u32_to_bytes(env, task.id));
+        id.extend_from_slice(b")");
+
+        let status = if task.completed {
+            badge(env, "done", BadgeKind::Success)
+        } else {
+            badge(env, "pending", BadgeKind::Warning)
+        };
+
+        let description = escape_markdown(env, &string_to_bytes(env, &task.description));
+        let description = if task.completed {
+            let mut struck = Bytes::from_slice(env, b"~~");
+            struck.append(&description);
+            struck.extend_from_slice(b"~~");
+            struck
+        } else {
+            description
+        };
+
+        let mut priority = Bytes::new(env);
+        if let Some(label) = priority_label(task.priority) {
+            priority.push_back(b'`');
+            priority.extend_from_slice(label.as_bytes());
+            priority.push_back(b'`');
         }
 
-        md.include(THEME_CONTRACT_ID, "footer").build()
+        MarkdownBuilder::new(env)
+            .action_row(&[id, status, description, priority], |md| {
+                let (label, method) = if task.completed {
+                    ("Reopen", "toggle_task")
+                } else {
+                    ("Done", "complete_task")
+                };
+                let actions = vec![
+                    env,
+                    (
+                        Bytes::from_slice(env, label.as_bytes()),
+                        Bytes::from_slice(env, method.as_bytes()),
+                        id_args(env, task.id),
+                    ),
+                ];
+                md.item_actions("", &actions)
+                    .text(" ")
+                    .raw_str("[Delete](render:/task/")
+                    .number(task.id)
+                    .raw_str("/delete)")
+            })
+            .build()
     }
 
-    fn render_single_task(env: &Env, tasks: &Map<u32, Task>, id: u32) -> Bytes {
+    fn render_single_task(env: &Env, tasks: &Map<u32, Task>, id: u32, pending: u32) -> Bytes {
         let mut md = MarkdownBuilder::new(env).h1("Task Details");
 
         if let Some(task) = tasks.get(id) {
@@ -452,22 +1478,49 @@ impl TodoContract {
                 .newline()
                 .newline()
                 .raw_str("**Description:** ")
-                .text_string(&task.description)
-                .newline()
+                .inline_form_with(
+                    "edit_task",
+                    &[("id", &u32_to_bytes(env, task.id))],
+                    "description",
+                    &string_to_bytes(env, &task.description),
+                )
                 .newline()
                 .raw_str("**Status:** ")
                 .text(status)
                 .newline()
+                .newline()
+                .raw_str("**Priority:** ")
+                .text(priority_label(task.priority).unwrap_or("none"))
+                .newline()
                 .newline();
 
-            // Action buttons
-            if !task.completed {
+            if let Some(due) = task.due {
                 md = md
-                    .tx_link_id("Mark Complete", "complete_task", task.id)
-                    .text(" | ");
+                    .raw_str("**Due:** ")
+                    .raw(u64_to_bytes(env, due))
+                    .raw_str(" (")
+                    .raw(relative_time(env, due))
+                    .raw_str(")");
+                if is_overdue(env, &task) {
+                    md = md.text(" ⚠ due");
+                }
+                md = md.newline().newline();
             }
+
+            // Action buttons
+            md = if task.completed {
+                md.checked_tx_link_id("Reopen", "toggle_task", task.id)
+            } else {
+                md.checked_tx_link_id("Mark Complete", "complete_task", task.id)
+            };
             md = md
-                .tx_link_id("Delete", "delete_task", task.id)
+                .text(" | ")
+                .raw_str("[Edit](render:/task/")
+                .number(task.id)
+                .raw_str("/edit) | ")
+                .raw_str("[Delete](render:/task/")
+                .number(task.id)
+                .raw_str("/delete)")
                 .newline()
                 .newline()
                 .render_link("Back to list", "/");
@@ -477,68 +1530,105 @@ impl TodoContract {
                 .render_link("Back to list", "/");
         }
 
-        md.build()
+        page(
+            env,
+            &theme_contract(env),
+            "header",
+            Self::render_nav(env, pending),
+            md.build(),
+            "footer",
+        )
+    }
+
+    /// Edit form for a task's description, prefilled with the current text
+    fn render_edit_task(env: &Env, tasks: &Map<u32, Task>, id: u32, pending: u32) -> Bytes {
+        let mut md = MarkdownBuilder::new(env).h1("Edit Task");
+
+        if let Some(task) = tasks.get(id) {
+            // The id rides along as a hidden field ahead of the description,
+            // matching edit_task's argument order; the viewer appends caller
+            let mut redirect = Bytes::from_slice(env, b"/task/");
+            redirect.append(&u32_to_bytes(env, id));
+
+            md = md
+                .hidden("id", &u32_to_bytes(env, id))
+                .hidden("_redirect", &redirect)
+                .textarea_value("description", 2, &string_to_bytes(env, &task.description))
+                .form_link("Save", "edit_task")
+                .text(" | ")
+                .raw_str("[Cancel](render:/task/")
+                .number(id)
+                .raw_str(")")
+                .newline();
+        } else {
+            md = md
+                .paragraph("*Task not found*")
+                .render_link("Back to list", "/tasks");
+        }
+
+        page(
+            env,
+            &theme_contract(env),
+            "header",
+            Self::render_nav(env, pending),
+            md.build(),
+            "footer",
+        )
+    }
+
+    fn render_delete_confirm(env: &Env, id: u32, pending: u32) -> Bytes {
+        let args = id_args(env, id);
+        let body = MarkdownBuilder::new(env)
+            .confirm_page("Deleting a task cannot be undone.", "delete_task", &args, "/tasks")
+            .build();
+        page(env, &theme_contract(env), "header", Self::render_nav(env, pending), body, "footer")
     }
 
     /// Render footer component - can be included via {{include contract=SELF func="footer"}}
     pub fn render_footer(env: Env, _path: Option<String>, _viewer: Option<Address>) -> Bytes {
         MarkdownBuilder::new(&env)
             .hr()
-            .paragraph("*Powered by [Soroban Render](https://github.com/wyhaines/soroban-render)*")
+            .raw_str("*Powered by ")
+            .with_link_refs(&env)
+            .link("Soroban Render", REPO_URL)
+            .with(|md| md.raw_str("*\n\n"))
             .build()
     }
 
-    /// Render header component - can be included via {{include contract=SELF func="header"}}
-    pub fn render_header(env: Env, _path: Option<String>, _viewer: Option<Address>) -> Bytes {
-        MarkdownBuilder::new(&env)
-            .h1("Todo List")
-            .paragraph("*A demo app showcasing Soroban Render*")
-            .hr()
-            .build()
+    /// Render header component - can be included via {{include contract=SELF func="header"}},
+    /// with an optional `args="{&quot;title&quot;:...}"` shown under the heading
+    pub fn render_header(env: Env, path: Option<String>, _viewer: Option<Address>) -> Bytes {
+        let mut md = MarkdownBuilder::new(&env).h1("Todo List");
+        if let Some(title) = include_arg(&path_to_bytes(&env, &path), "title") {
+            md = md.raw_str("## ").raw(escape_markdown(&env, &title)).raw_str("\n\n");
+        }
+        md.paragraph("*A demo app showcasing Soroban Render*").hr().build()
     }
 
     fn render_json(
         env: &Env,
         tasks: &Map<u32, Task>,
-        subpath: Option<Bytes>,
+        filter: Option<bool>,
         wallet_connected: bool,
     ) -> Bytes {
-        // Determine filter from subpath
-        let filter = if let Some(ref sp) = subpath {
-            let pending_bytes = Bytes::from_slice(env, b"/pending");
-            let completed_bytes = Bytes::from_slice(env, b"/completed");
-            if *sp == pending_bytes {
-                Some(false)
-            } else if *sp == completed_bytes {
-                Some(true)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
-
         let mut doc = JsonDocument::new(env, "Todo List").heading(1, "Todo List");
 
         if !wallet_connected {
             doc = doc
                 .heading(2, "Connect Your Wallet")
                 .text("Please connect your wallet to view and manage your personal todo list.")
-                .text("Each user has their own private task list that only they can see and modify.");
+                .text("Each user has their own task list that only they can modify.");
         } else {
             // Form for adding tasks
             doc = doc
                 .form("add_task")
                 .text_field("description", "Enter task description", true)
+                .text_field("due", "Due (ledger timestamp)", false)
+                .text_field("priority", "Priority (0 = none, 3 = high)", true)
                 .submit("Add Task");
 
             // Navigation
-            doc = doc
-                .nav_start()
-                .nav_item("All", "/json", filter.is_none(), true)
-                .nav_item("Pending", "/json/pending", filter == Some(false), false)
-                .nav_item("Completed", "/json/completed", filter == Some(true), false)
-                .nav_end();
+            doc = NavBuilder::new(&filter_nav_items(filter)).json(doc);
 
             // Count completed vs pending for chart
             let mut completed_count = 0u32;
@@ -563,34 +1653,14 @@ impl TodoContract {
             // Tasks heading
             doc = doc.heading(2, "Your Tasks").container_start("task-list");
 
-            let mut task_count = 0u32;
-            for (_, task) in tasks.iter() {
-                // Apply filter
-                if let Some(completed_filter) = filter {
-                    if task.completed != completed_filter {
-                        continue;
-                    }
-                }
-
-                // Build task with actions
-                let mut task_builder = doc.task_string(task.id, &task.description, task.completed);
-
-                if !task.completed {
-                    task_builder = task_builder.tx_action("complete_task", task.id, "Done");
-                }
-                task_builder = task_builder.tx_action("delete_task", task.id, "Delete");
-
-                doc = task_builder.end();
-                task_count += 1;
+            // Filtered, in ascending id order
+            let visible = sorted_tasks(env, tasks, filter, None, SortOrder::Id);
+            for task in visible.iter() {
+                doc = Self::json_task(env, doc, &task);
             }
 
-            // If no tasks, add a text component
-            if task_count == 0 {
-                doc = if filter.is_some() {
-                    doc.text("No matching tasks.")
-                } else {
-                    doc.text("No tasks yet. Add one above!")
-                };
+            if visible.is_empty() {
+                doc = json::empty_state(doc, empty_tasks_message(filter.is_some()));
             }
 
             doc = doc.container_end();
@@ -601,12 +1671,66 @@ impl TodoContract {
             .text("Powered by Soroban Render")
             .build()
     }
+
+    /// JSON detail view for `/json/task/:id`: a single task component, or a
+    /// "not found" text component when the viewer has no task with that id
+    fn render_json_task(env: &Env, tasks: &Map<u32, Task>, id: u32) -> Bytes {
+        let doc = JsonDocument::new(env, "Task Details").heading(1, "Task Details");
+        let doc = match tasks.get(id) {
+            Some(task) => Self::json_task(env, doc, &task),
+            None => doc.text("Task not found."),
+        };
+        doc.build()
+    }
+
+    /// JSON counterpart of the 404 page for routes with no JSON view
+    fn render_json_404(env: &Env) -> Bytes {
+        JsonDocument::new(env, "Page Not Found")
+            .heading(1, "Page Not Found")
+            .text("No JSON view exists for this path.")
+            .build()
+    }
+
+    /// Append a task component with its actions, priority and overdue notes
+    fn json_task<'a>(env: &Env, doc: JsonDocument<'a>, task: &Task) -> JsonDocument<'a> {
+        let mut task_builder = doc.task_string(task.id, &task.description, task.completed);
+
+        if task.completed {
+            task_builder = task_builder.tx_action("toggle_task", task.id, "Reopen");
+        } else {
+            task_builder = task_builder.tx_action("complete_task", task.id, "Done");
+        }
+        task_builder = task_builder.tx_action("delete_task", task.id, "Delete");
+
+        let mut doc = task_builder.end();
+        if task.priority > 0 {
+            doc = doc.text(match task.priority {
+                1 => "Priority: low",
+                2 => "Priority: medium",
+                _ => "Priority: high",
+            });
+        }
+        if is_overdue(env, task) {
+            doc = doc.text("⚠ Overdue");
+        }
+        doc
+    }
 }
 
 #[cfg(test)]
 mod test {
+    extern crate std;
+
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::IntoVal;
+
+    /// Copy rendered output into a std string for assertions
+    fn to_str(bytes: &Bytes) -> std::string::String {
+        let mut buf = std::vec![0u8; bytes.len() as usize];
+        bytes.copy_into_slice(&mut buf);
+        std::string::String::from_utf8(buf).unwrap()
+    }
 
     #[test]
     fn test_add_and_get_task() {
@@ -619,7 +1743,7 @@ mod test {
         let user = Address::generate(&env);
 
         // Add a task (init is no longer required)
-        let task_id = client.add_task(&String::from_str(&env, "Buy groceries"), &user);
+        let task_id = client.add_task(&String::from_str(&env, "Buy groceries"), &None, &0, &user);
         assert_eq!(task_id, 1);
 
         // Get the task (now requires user address)
@@ -640,7 +1764,7 @@ mod test {
 
         let user = Address::generate(&env);
 
-        client.add_task(&String::from_str(&env, "Test task"), &user);
+        client.add_task(&String::from_str(&env, "Test task"), &None, &0, &user);
 
         // Complete the task
         client.complete_task(&1, &user);
@@ -661,10 +1785,10 @@ mod test {
         let user2 = Address::generate(&env);
 
         // User 1 adds a task
-        client.add_task(&String::from_str(&env, "User1 task"), &user1);
+        client.add_task(&String::from_str(&env, "User1 task"), &None, &0, &user1);
 
         // User 2 adds a task
-        client.add_task(&String::from_str(&env, "User2 task"), &user2);
+        client.add_task(&String::from_str(&env, "User2 task"), &None, &0, &user2);
 
         // User 1 should only see their task
         let user1_tasks = client.get_tasks(&user1);
@@ -692,14 +1816,7 @@ mod test {
         // Render home page without viewer
         let output = client.render(&None, &None);
 
-        let mut bytes_vec: [u8; 2048] = [0; 2048];
-        let len = output.len() as usize;
-        for i in 0..len {
-            if let Some(b) = output.get(i as u32) {
-                bytes_vec[i] = b;
-            }
-        }
-        let output_str = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+        let output_str = to_str(&output);
 
         // Check for home page content
         assert!(output_str.contains("Welcome to the Soroban Render Demo"));
@@ -722,14 +1839,7 @@ mod test {
         let tasks_path = String::from_str(&env, "/tasks");
         let output = client.render(&Some(tasks_path), &None);
 
-        let mut bytes_vec: [u8; 2048] = [0; 2048];
-        let len = output.len() as usize;
-        for i in 0..len {
-            if let Some(b) = output.get(i as u32) {
-                bytes_vec[i] = b;
-            }
-        }
-        let output_str = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+        let output_str = to_str(&output);
 
         assert!(output_str.contains("Connect Your Wallet"));
         assert!(output_str.contains("personal todo list"));
@@ -745,21 +1855,14 @@ mod test {
 
         let user = Address::generate(&env);
 
-        client.add_task(&String::from_str(&env, "First task"), &user);
-        client.add_task(&String::from_str(&env, "Second task"), &user);
+        client.add_task(&String::from_str(&env, "First task"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Second task"), &None, &0, &user);
 
         // Render tasks page with viewer - should show tasks
         let tasks_path = String::from_str(&env, "/tasks");
         let output = client.render(&Some(tasks_path), &Some(user));
 
-        let mut bytes_vec: [u8; 2048] = [0; 2048];
-        let len = output.len() as usize;
-        for i in 0..len {
-            if let Some(b) = output.get(i as u32) {
-                bytes_vec[i] = b;
-            }
-        }
-        let output_str = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+        let output_str = to_str(&output);
 
         // Check for include tags
         assert!(output_str.contains("{{include contract=CCYEOY2JTOQ2JIMLLERAFNHAVKEKMEJDBOTLN6DIIWBHWEIMUA2T2VY4 func=\"header\"}}"));
@@ -768,6 +1871,7 @@ mod test {
         assert!(output_str.contains("First task"));
         assert!(output_str.contains("Second task"));
         assert!(output_str.contains("Your Tasks"));
+        assert!(output_str.contains("<span class=\"legend\">`high` priority · ⚠ past due</span>"));
     }
 
     #[test]
@@ -781,21 +1885,14 @@ mod test {
         let user = Address::generate(&env);
 
         // Add some tasks to generate stats
-        client.add_task(&String::from_str(&env, "Task 1"), &user);
-        client.add_task(&String::from_str(&env, "Task 2"), &user);
+        client.add_task(&String::from_str(&env, "Task 1"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Task 2"), &None, &0, &user);
 
         // Render about page
         let about_path = String::from_str(&env, "/about");
         let output = client.render(&Some(about_path), &None);
 
-        let mut bytes_vec: [u8; 3072] = [0; 3072];
-        let len = output.len() as usize;
-        for i in 0..len {
-            if let Some(b) = output.get(i as u32) {
-                bytes_vec[i] = b;
-            }
-        }
-        let output_str = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+        let output_str = to_str(&output);
 
         // Check for about page content
         assert!(output_str.contains("About Soroban Render"));
@@ -807,6 +1904,27 @@ mod test {
         assert!(output_str.contains("1")); // The number
         // Check for INFO alert
         assert!(output_str.contains("[!INFO]"));
+        // Links are references, with each URL written once
+        assert!(output_str.contains("- [View the source code on GitHub][2]\n"));
+        assert!(output_str.contains("\n[2]: https://github.com/wyhaines/soroban-render\n"));
+        assert!(!output_str.contains("](https://"));
+        // The embedding example names this contract and isn't resolved
+        let tag = env.as_contract(&contract_id, || {
+            Include::this(&env).func("header").args("{\"title\":\"Shared list\"}").build()
+        });
+        let mut expected = std::string::String::from("`{{noparse}}");
+        expected.push_str(&to_str(&tag));
+        expected.push_str("{{/noparse}}`");
+        assert!(output_str.contains(&expected));
+        assert!(expected.contains(&to_str(&string_to_bytes(&env, &contract_id.to_string()))));
+        assert!(expected.contains(" func=\"header\" args=\"{&quot;title&quot;:&quot;Shared list&quot;}\"}}"));
+
+        // The included header shows the title it was passed
+        let args = String::from_str(&env, "{\"title\":\"Shared list\"}");
+        let header = to_str(&client.render_header(&Some(args), &None));
+        assert!(header.starts_with("# Todo List\n\n## Shared list\n\n"));
+        let header = to_str(&client.render_header(&None, &None));
+        assert!(!header.contains("## "));
     }
 
     #[test]
@@ -819,8 +1937,8 @@ mod test {
 
         let user = Address::generate(&env);
 
-        client.add_task(&String::from_str(&env, "First task"), &user);
-        client.add_task(&String::from_str(&env, "Second task"), &user);
+        client.add_task(&String::from_str(&env, "First task"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Second task"), &None, &0, &user);
 
         // Complete one task to have mixed stats
         client.complete_task(&1, &user);
@@ -829,14 +1947,7 @@ mod test {
         let json_path = String::from_str(&env, "/json");
         let output = client.render(&Some(json_path), &Some(user));
 
-        let mut bytes_vec: [u8; 2048] = [0; 2048];
-        let len = output.len() as usize;
-        for i in 0..len {
-            if let Some(b) = output.get(i as u32) {
-                bytes_vec[i] = b;
-            }
-        }
-        let output_str = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+        let output_str = to_str(&output);
 
         // Check JSON structure
         assert!(output_str.contains("\"format\":\"soroban-render-json-v1\""));
@@ -853,6 +1964,39 @@ mod test {
         assert!(output_str.contains("\"label\":\"Pending\""));
     }
 
+    #[test]
+    fn test_add_task_form_with_blank_due() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+
+        // The due field is optional on the JSON form
+        let output = client.render(&Some(String::from_str(&env, "/json")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        let due_field = &output_str[output_str.find("\"name\":\"due\"").unwrap()..];
+        let due_field = &due_field[..due_field.find('}').unwrap()];
+        assert!(!due_field.contains("required"));
+
+        // Submit the form args as the viewer does for a blank due: void in
+        // due's position, then priority, then the caller
+        let args: Vec<soroban_sdk::Val> = soroban_sdk::vec![
+            &env,
+            String::from_str(&env, "No deadline").into_val(&env),
+            ().into_val(&env),
+            2u32.into_val(&env),
+            user.clone().into_val(&env),
+        ];
+        let id: u32 = env.invoke_contract(&contract_id, &Symbol::new(&env, "add_task"), args);
+
+        let task = client.get_task(&id, &user).unwrap();
+        assert_eq!(task.due, None);
+        assert_eq!(task.priority, 2);
+    }
+
     #[test]
     fn test_render_json_without_wallet() {
         let env = Env::default();
@@ -865,18 +2009,801 @@ mod test {
         let json_path = String::from_str(&env, "/json");
         let output = client.render(&Some(json_path), &None);
 
-        let mut bytes_vec: [u8; 1024] = [0; 1024];
-        let len = output.len() as usize;
-        for i in 0..len {
-            if let Some(b) = output.get(i as u32) {
-                bytes_vec[i] = b;
-            }
-        }
-        let output_str = core::str::from_utf8(&bytes_vec[..len]).unwrap();
+        let output_str = to_str(&output);
 
         // Should show connect wallet message
         assert!(output_str.contains("Connect Your Wallet"));
         // Should NOT show form or navigation
         assert!(!output_str.contains("\"type\":\"form\""));
     }
+
+    #[test]
+    fn test_render_delete_confirm_page() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "Doomed task"), &None, &0, &user);
+
+        let path = String::from_str(&env, "/task/1/delete");
+        let output = client.render(&Some(path), &Some(user));
+
+        let output_str = to_str(&output);
+
+        assert!(output_str.contains("[Confirm](tx:delete_task {\"id\":1})"));
+        assert!(output_str.contains("[Cancel](render:/tasks)"));
+        assert!(output_str.starts_with("{{include contract="));
+    }
+
+    #[test]
+    fn test_route_title_slot() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "Titled task"), &None, &0, &user);
+
+        // The task page fills the nav's title slot
+        let path = String::from_str(&env, "/task/1/edit");
+        let output_str = to_str(&client.render(&Some(path), &Some(user.clone())));
+        assert!(output_str.contains("[About](render:/about) › Edit Task #1\n"));
+        assert!(!output_str.contains("{{slot"));
+
+        // Other routes leave it empty
+        let path = String::from_str(&env, "/tasks");
+        let output_str = to_str(&client.render(&Some(path), &Some(user)));
+        assert!(output_str.contains("[About](render:/about)\n"));
+        assert!(!output_str.contains("{{slot"));
+    }
+
+    #[test]
+    fn test_page_shell_order() {
+        let env = Env::default();
+
+        let nav = Bytes::from_slice(&env, b"[Home](render:/)\n\n");
+        let body = Bytes::from_slice(&env, b"Page body\n");
+        // The theme address is read from contract storage
+        let contract_id = env.register(TodoContract, ());
+        let output = env.as_contract(&contract_id, || page(&env, &theme_contract(&env), "header", nav, body, "footer"));
+
+        let output_str = to_str(&output);
+
+        let header = output_str.find("func=\"header\"}}").unwrap();
+        let nav = output_str.find("[Home](render:/)").unwrap();
+        let body = output_str.find("Page body").unwrap();
+        let footer = output_str.find("func=\"footer\"}}").unwrap();
+        assert!(header < nav && nav < body && body < footer);
+    }
+
+    #[test]
+    fn test_render_tasks_fragment() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "First task"), &None, &0, &user);
+
+        let path = String::from_str(&env, "/tasks");
+        let output = client.render_fragment(&Some(path), &Some(user), &symbol_short!("tasks"));
+
+        let output_str = to_str(&output);
+
+        // Only the list, not the page shell
+        assert!(output_str.contains("First task"));
+        assert!(!output_str.contains("{{include"));
+        assert!(!output_str.contains("Add Task"));
+    }
+
+    #[test]
+    fn test_render_add_task_textarea() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+
+        let tasks_path = String::from_str(&env, "/tasks");
+        let output = client.render(&Some(tasks_path), &Some(user));
+
+        let output_str = to_str(&output);
+
+        // The add-task form must match the viewer's <textarea> convention exactly
+        assert!(output_str.contains(
+            "<textarea name=\"description\" rows=\"2\" placeholder=\"What needs to be done?\"></textarea>"
+        ));
+        assert!(output_str.contains(
+            "<select name=\"priority\"><option value=\"0\">No priority</option><option value=\"1\">Low</option>"
+        ));
+        assert!(output_str.contains(
+            "<input type=\"hidden\" name=\"_redirect\" value=\"/tasks?msg=Task+added\" />"
+        ));
+        assert!(output_str.contains(
+            "[Add Task](form:add_task) <span class=\"shortcut-hint\"><kbd>N</kbd> Add task</span>\n\n## Filter"
+        ));
+    }
+
+    #[test]
+    fn test_render_task_list_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        for _ in 0..25 {
+            client.add_task(&String::from_str(&env, "Task"), &None, &0, &user);
+        }
+
+        let path = String::from_str(&env, "/tasks?page=2&per_page=10");
+        let output = client.render(&Some(path), &Some(user));
+
+        let output_str = to_str(&output);
+
+        // Page 2 holds tasks 11-20 only
+        assert!(!output_str.contains("| [#10]("));
+        assert!(output_str.contains("| [#11]("));
+        assert!(output_str.contains("| [#20]("));
+        assert!(!output_str.contains("| [#21]("));
+        assert!(output_str.contains("[Previous](render:/tasks?page=1)"));
+        assert!(output_str.contains("[Next](render:/tasks?page=3)"));
+    }
+
+    #[test]
+    fn test_render_task_list_sort() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "First task"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Second task"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Third task"), &None, &0, &user);
+        client.complete_task(&1, &user);
+
+        // Newest first
+        let path = String::from_str(&env, "/tasks?sort=id_desc");
+        let output = client.render(&Some(path), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.find("| [#3](").unwrap() < output_str.find("| [#1](").unwrap());
+
+        // Pending before completed
+        let path = String::from_str(&env, "/tasks?sort=status");
+        let output = client.render(&Some(path), &Some(user));
+        let output_str = to_str(&output);
+        let first = output_str.find("| [#1](").unwrap();
+        assert!(output_str.find("| [#2](").unwrap() < first);
+        assert!(output_str.find("| [#3](").unwrap() < first);
+    }
+
+    #[test]
+    fn test_render_overdue_marker() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "Late task"), &Some(500), &0, &user);
+        client.add_task(&String::from_str(&env, "Future task"), &Some(5_000), &0, &user);
+
+        let path = String::from_str(&env, "/tasks");
+        let output = client.render(&Some(path), &Some(user.clone()));
+
+        let output_str = to_str(&output);
+
+        // Only the past-due task is flagged, ahead of the rows
+        assert!(output_str.contains("<span class=\"badge badge-danger\">overdue</span> [#1](render:/task/1)\n"));
+        assert!(!output_str.contains(", [#2](render:/task/2)"));
+
+        // The stored row carries no time-dependent marker
+        let name = task_rows_name(&env, &user);
+        let collection = Symbol::new(&env, core::str::from_utf8(&name).unwrap());
+        let row = client.get_chunk(&collection, &0).unwrap();
+        assert_eq!(row, TodoContract::render_task_row(&env, &client.get_task(&1, &user).unwrap()));
+        assert!(!output_str[output_str.find("Late task").unwrap()..].contains("overdue"));
+
+        let task = client.get_task(&1, &user).unwrap();
+        assert_eq!(task.due, Some(500));
+        assert_eq!(task.priority, 0);
+    }
+
+    #[test]
+    fn test_render_priority_order() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "Low"), &None, &1, &user);
+        client.add_task(&String::from_str(&env, "High"), &None, &3, &user);
+        client.add_task(&String::from_str(&env, "Medium"), &None, &2, &user);
+
+        let path = String::from_str(&env, "/tasks?sort=priority");
+        let output = client.render(&Some(path), &Some(user.clone()));
+
+        let output_str = to_str(&output);
+
+        let high = output_str.find("| High | `high` |").unwrap();
+        let medium = output_str.find("| Medium | `medium` |").unwrap();
+        let low = output_str.find("| Low | `low` |").unwrap();
+        assert!(high < medium);
+        assert!(medium < low);
+        assert!(output_str.contains("| [Priority ▲](render:/tasks?sort=-priority) |"));
+
+        // The header's reverse link lists the lowest priority first
+        let path = String::from_str(&env, "/tasks?sort=-priority");
+        let output_str = to_str(&client.render(&Some(path), &Some(user.clone())));
+        assert!(output_str.find("| Low | `low` |").unwrap() < output_str.find("| High | `high` |").unwrap());
+        assert!(output_str.contains("| [Priority ▼](render:/tasks?sort=priority) |"));
+
+        // `-id` and the older `id_desc` both list the newest first
+        for sort in ["/tasks?sort=-id", "/tasks?sort=id_desc"] {
+            let output_str = to_str(&client.render(&Some(String::from_str(&env, sort)), &Some(user.clone())));
+            assert!(output_str.find("| Medium |").unwrap() < output_str.find("| Low |").unwrap());
+            assert!(output_str.contains("| [# ▼](render:/tasks?sort=id) |"));
+        }
+    }
+
+    #[test]
+    fn test_search_tasks() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "Buy Groceries"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Walk the dog"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Pay rent"), &None, &0, &user);
+
+        let found = client.search_tasks(&user, &String::from_str(&env, "groceries"));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found.get(0).unwrap().id, 1);
+
+        let path = String::from_str(&env, "/tasks?q=GROCERIES");
+        let output = client.render(&Some(path), &Some(user.clone()));
+
+        let output_str = to_str(&output);
+
+        assert!(output_str.contains("Buy Groceries"));
+        assert!(!output_str.contains("Walk the dog"));
+        assert!(!output_str.contains("Pay rent"));
+
+        // Paging links carry the search re-encoded
+        client.add_task(&String::from_str(&env, "Buy (more) groceries"), &None, &0, &user);
+        let path = String::from_str(&env, "/tasks?q=(more) groceries&per_page=1&page=2");
+        let output = to_str(&client.render(&Some(path), &Some(user)));
+        assert!(output.contains("[Previous](render:/tasks?page=1&per_page=1&q=%28more%29%20groceries)"));
+    }
+
+    #[test]
+    fn test_render_json_unknown_route() {
+        let env = Env::default();
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        for path in ["/nosuch.json", "/json/nosuch"] {
+            let output = client.render(&Some(String::from_str(&env, path)), &Some(user.clone()));
+            let output_str = to_str(&output);
+            assert!(output_str.contains("\"format\":\"soroban-render-json-v1\""));
+            assert!(output_str.contains("Page Not Found"));
+            assert!(!output_str.contains("\"type\":\"form\""));
+        }
+    }
+
+    #[test]
+    fn test_render_json_task_detail() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        for i in 0..12 {
+            let description = if i == 1 { "Second task" } else { "Other task" };
+            client.add_task(&String::from_str(&env, description), &None, &0, &user);
+        }
+
+        let output = client.render(&Some(String::from_str(&env, "/json/task/2")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert_eq!(output_str.matches("\"type\":\"task\"").count(), 1);
+        assert!(output_str.contains("\"id\":2,"));
+        assert!(output_str.contains("Second task"));
+
+        // Multi-digit ids resolve too
+        let output = client.render(&Some(String::from_str(&env, "/json/task/12")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("\"id\":12,"));
+
+        let output = client.render(&Some(String::from_str(&env, "/json/task/99")), &Some(user));
+        let output_str = to_str(&output);
+        assert!(!output_str.contains("\"type\":\"task\""));
+        assert!(output_str.contains("Task not found."));
+    }
+
+    #[test]
+    fn test_render_sitemap() {
+        let env = Env::default();
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let output = client.render(&Some(String::from_str(&env, "/_sitemap")), &None);
+        let output_str = to_str(&output);
+        assert!(output_str.contains("# Sitemap"));
+        for (path, _) in ROUTES {
+            assert!(output_str.contains(path));
+        }
+        assert!(output_str.contains("- Task details - `/task/{id}`"));
+        assert!(output_str.contains("[Pending tasks](render:/tasks/pending)"));
+        assert!(!output_str.contains("render:/task/{id}"));
+        // Wrapped in the page shell like the other routes
+        assert!(output_str.starts_with("{{include contract="));
+        assert!(output_str.contains("func=\"footer\"}}"));
+    }
+
+    #[test]
+    fn test_message_toast() {
+        let env = Env::default();
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        let path = String::from_str(&env, "/tasks/pending?msg=Task+added%21");
+        let output = client.render(&Some(path), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("> [!TIP]\n> Task added\\! [Dismiss](render:/tasks/pending)"));
+        assert!(output_str.contains("name=\"_redirect\" value=\"/tasks/pending?msg=Task+added\""));
+
+        // No message, no toast
+        let output = client.render(&Some(String::from_str(&env, "/tasks")), &Some(user));
+        let output_str = to_str(&output);
+        assert!(!output_str.contains("[!TIP]"));
+    }
+
+    #[test]
+    fn test_set_theme() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.init(&admin);
+
+        let theme = env.register(TodoContract, ());
+        client.set_theme(&theme);
+        assert_eq!(env.auths()[0].0, admin);
+
+        let output = client.render(&Some(String::from_str(&env, "/about")), &None);
+        let output_str = to_str(&output);
+
+        let tag = Include::contract(&theme).func("header").build();
+        assert!(output_str.contains(&to_str(&tag)));
+        assert!(!output_str.contains(THEME_CONTRACT_ID));
+    }
+
+    #[test]
+    #[should_panic(expected = "Already initialized")]
+    fn test_init_twice() {
+        let env = Env::default();
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        client.init(&Address::generate(&env));
+        client.init(&Address::generate(&env));
+    }
+
+    #[test]
+    fn test_render_etag() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+        let path = Some(String::from_str(&env, "/tasks"));
+
+        let before = client.render_etag(&path, &Some(user.clone()));
+        assert_eq!(client.render_etag(&path, &Some(user.clone())), before);
+
+        client.add_task(&String::from_str(&env, "New task"), &None, &0, &user);
+        assert_ne!(client.render_etag(&path, &Some(user)), before);
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+        client.add_task(&String::from_str(&env, "Open task"), &None, &0, &user);
+
+        let tasks = Some(String::from_str(&env, "/tasks"));
+        let same = to_str(&client.render_diff(&tasks, &tasks, &Some(user.clone())));
+        assert!(same.starts_with("```diff\n"));
+        assert!(!same.contains("\n-") && !same.contains("\n+"));
+
+        let completed = Some(String::from_str(&env, "/tasks/completed"));
+        let changed = to_str(&client.render_diff(&tasks, &completed, &Some(user)));
+        // The open task drops out of the completed list
+        assert!(changed.lines().any(|l| l.starts_with('-') && l.contains("Open task")));
+        assert!(!changed.lines().any(|l| l.starts_with('+') && l.contains("Open task")));
+    }
+
+    #[test]
+    fn test_stable_id_order() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        for _ in 0..4 {
+            client.add_task(&String::from_str(&env, "Task"), &None, &0, &user);
+        }
+        // Complete out of id order
+        client.complete_task(&3, &user);
+        client.complete_task(&1, &user);
+
+        let tasks = client.get_tasks(&user);
+        for i in 0..tasks.len() {
+            assert_eq!(tasks.get(i).unwrap().id, i + 1);
+        }
+
+        let output = client.render(&Some(String::from_str(&env, "/json")), &Some(user));
+        let output_str = to_str(&output);
+        let first = output_str.find("\"type\":\"task\",\"id\":1,").unwrap();
+        let second = output_str.find("\"type\":\"task\",\"id\":2,").unwrap();
+        let third = output_str.find("\"type\":\"task\",\"id\":3,").unwrap();
+        let fourth = output_str.find("\"type\":\"task\",\"id\":4,").unwrap();
+        assert!(first < second && second < third && third < fourth);
+    }
+
+    #[test]
+    fn test_task_list_continuation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        for _ in 0..30 {
+            client.add_task(&String::from_str(&env, "Task"), &None, &0, &user);
+        }
+
+        let output = client.render(&Some(String::from_str(&env, "/tasks")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        let marker = output_str.find("{{continue").unwrap();
+        assert!(output_str.contains(
+            "| [# ▲](render:/tasks?sort=-id) | Status | Task | [Priority](render:/tasks?sort=priority) | Actions |\n|---|---|---|---|---|\n"
+        ));
+        assert_eq!(output_str[..marker].matches("| [#").count(), DEFAULT_PER_PAGE as usize + 1);
+        // The marker sits directly below the last row, inside the table
+        assert!(output_str[..marker].ends_with(" |\n"));
+        assert!(output_str[marker..].contains("from=10 total=30"));
+        assert!(!output_str.contains("[Next]"));
+
+        let name = task_rows_name(&env, &user);
+        let collection = Symbol::new(&env, core::str::from_utf8(&name).unwrap());
+        assert!(output_str.contains(core::str::from_utf8(&name).unwrap()));
+
+        let chunk = client.get_chunk(&collection, &10).unwrap();
+        assert!(to_str(&chunk).contains("| [#11]("));
+        assert!(client.get_chunk(&collection, &30).is_none());
+
+        // Changing a task rewrites its row in place
+        client.complete_task(&11, &user);
+        let chunk = client.get_chunk(&collection, &10).unwrap();
+        assert!(to_str(&chunk).contains("badge-success"));
+
+        // Deleting a task drops its row and shifts the rest down
+        client.delete_task(&30, &user);
+        assert!(client.get_chunk(&collection, &29).is_none());
+        client.delete_task(&1, &user);
+        let chunk = client.get_chunk(&collection, &9).unwrap();
+        assert!(to_str(&chunk).contains("| [#11]("));
+        assert!(client.get_chunk(&collection, &28).is_none());
+    }
+
+    #[test]
+    fn test_empty_state() {
+        let env = Env::default();
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        let output = client.render(&Some(String::from_str(&env, "/tasks")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("<p class=\"empty\"><em>No tasks yet. Add one above!</em></p>"));
+
+        let output = client.render(&Some(String::from_str(&env, "/json/pending")), &Some(user));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("\"className\":\"empty\""));
+        assert!(output_str.contains("No matching tasks."));
+    }
+
+    #[test]
+    fn test_render_404() {
+        let env = Env::default();
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        let output = client.render(&Some(String::from_str(&env, "/nonexistent")), &None);
+        let output_str = to_str(&output);
+        assert!(output_str.contains("Page not found: `/nonexistent`"));
+        assert!(output_str.contains("[Back to Home](render:/)"));
+        assert!(!output_str.contains("Welcome"));
+
+        // A path can't break out of the code span
+        let output = client.render(&Some(String::from_str(&env, "/x`[a](tx:evil)")), &None);
+        let output_str = to_str(&output);
+        assert!(output_str.contains("Page not found: `/x'[a](tx:evil)`"));
+    }
+
+    #[test]
+    fn test_leaderboard() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let light = Address::generate(&env);
+        let heavy = Address::generate(&env);
+
+        client.add_task(&String::from_str(&env, "Only task"), &None, &0, &light);
+        for _ in 0..3 {
+            client.add_task(&String::from_str(&env, "Task"), &None, &0, &heavy);
+        }
+        client.complete_task(&1, &heavy);
+
+        let users = client.get_users(&0, &10);
+        assert_eq!(users.len(), 2);
+        assert_eq!(users.get(0).unwrap(), light);
+        assert_eq!(client.get_users(&1, &10), soroban_sdk::vec![&env, heavy.clone()]);
+
+        let output = client.render(&Some(String::from_str(&env, "/leaderboard")), &None);
+        let output_str = to_str(&output);
+
+        let heavy_short = to_str(&address_short(&env, &heavy));
+        let first = output_str.find("| 1 | `").unwrap() + "| 1 | `".len();
+        assert_eq!(&output_str[first..first + heavy_short.len()], heavy_short);
+        assert!(output_str.contains("` | 3 | 1 |"));
+        assert!(output_str.contains("` | 1 | 0 |"));
+    }
+
+    #[test]
+    fn test_leaderboard_is_bounded() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+
+        // User n adds n tasks, so the first two rank below the other ten
+        let mut users: Vec<Address> = Vec::new(&env);
+        for n in 1..=LEADERBOARD_SIZE + 2 {
+            let user = Address::generate(&env);
+            for _ in 0..n {
+                client.add_task(&String::from_str(&env, "Task"), &None, &0, &user);
+            }
+            users.push_back(user);
+        }
+
+        let board: Vec<LeaderboardEntry> = env.as_contract(&contract_id, || {
+            env.storage().persistent().get(&DataKey::Leaderboard).unwrap()
+        });
+        assert_eq!(board.len(), LEADERBOARD_SIZE);
+        assert_eq!(board.get(0).unwrap().user, users.last().unwrap());
+        assert_eq!(board.get(0).unwrap().tasks, LEADERBOARD_SIZE + 2);
+        assert!(!board.iter().any(|entry| entry.user == users.get(0).unwrap()));
+
+        // Clearing a user's tasks takes them off the board
+        let top = users.last().unwrap();
+        for id in 1..=LEADERBOARD_SIZE + 2 {
+            client.delete_task(&id, &top);
+        }
+        let board: Vec<LeaderboardEntry> = env.as_contract(&contract_id, || {
+            env.storage().persistent().get(&DataKey::Leaderboard).unwrap()
+        });
+        assert!(!board.iter().any(|entry| entry.user == top));
+        assert_eq!(client.get_users(&0, &100).len(), LEADERBOARD_SIZE + 2);
+    }
+
+    #[test]
+    fn test_completed_count() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        client.add_task(&String::from_str(&env, "Done then deleted"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Deleted while pending"), &None, &0, &user);
+        client.add_task(&String::from_str(&env, "Kept"), &None, &0, &user);
+        assert_eq!(client.get_completed_count(), 0);
+
+        client.complete_task(&1, &user);
+        client.complete_task(&3, &user);
+        // Completing twice counts once
+        client.complete_task(&3, &user);
+        assert_eq!(client.get_completed_count(), 2);
+
+        client.delete_task(&2, &user);
+        assert_eq!(client.get_completed_count(), 2);
+        client.delete_task(&1, &user);
+        assert_eq!(client.get_completed_count(), 1);
+        assert_eq!(client.get_stats().0, 1);
+
+        // Deleting a missing task changes nothing
+        client.delete_task(&1, &user);
+        assert_eq!(client.get_completed_count(), 1);
+        assert_eq!(client.get_stats().0, 1);
+
+        let output = client.render(&Some(String::from_str(&env, "/about")), &None);
+        let output_str = to_str(&output);
+        assert!(output_str.contains("**Completed**\n\n# 100%"));
+    }
+
+    #[test]
+    fn test_toggle_task() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        client.add_task(&String::from_str(&env, "Oops"), &None, &0, &user);
+        client.complete_task(&1, &user);
+        assert_eq!(client.get_completed_count(), 1);
+
+        let output = client.render(&Some(String::from_str(&env, "/tasks")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("[Reopen](tx:toggle_task {\"id\":1})"));
+
+        let output = client.render(&Some(String::from_str(&env, "/json")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("\"method\":\"toggle_task\""));
+        assert!(output_str.contains("Reopen"));
+
+        client.toggle_task(&1, &user);
+        assert!(!client.get_task(&1, &user).unwrap().completed);
+        assert_eq!(client.get_completed_count(), 0);
+
+        client.toggle_task(&1, &user);
+        assert!(client.get_task(&1, &user).unwrap().completed);
+        assert_eq!(client.get_completed_count(), 1);
+    }
+
+    #[test]
+    fn test_edit_task() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        client.add_task(&String::from_str(&env, "Buy <milk> & eggs"), &None, &0, &user);
+
+        let output = client.render(&Some(String::from_str(&env, "/task/1/edit")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("<input type=\"hidden\" name=\"id\" value=\"1\" />"));
+        assert!(output_str.contains(
+            "<textarea name=\"description\" rows=\"2\">Buy &lt;milk&gt; &amp; eggs</textarea>"
+        ));
+        assert!(output_str.contains("[Save](form:edit_task)"));
+
+        client.edit_task(&1, &String::from_str(&env, "Buy milk and eggs"), &user);
+        assert_eq!(
+            client.get_task(&1, &user).unwrap().description,
+            String::from_str(&env, "Buy milk and eggs")
+        );
+
+        let output = client.render(&Some(String::from_str(&env, "/task/1")), &Some(user));
+        let output_str = to_str(&output);
+        // The description is also editable in place, carrying the task id
+        assert!(output_str.contains(
+            "<input type=\"hidden\" name=\"id\" value=\"1\" /><input type=\"text\" name=\"description\" value=\"Buy milk and eggs\" /> [Save](form:edit_task)"
+        ));
+        assert!(output_str.contains("[Edit](render:/task/1/edit)"));
+    }
+
+    #[test]
+    fn test_acknowledge_notice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        let path = String::from_str(&env, "/tasks");
+        let output = to_str(&client.render(&Some(path.clone()), &Some(user.clone())));
+        assert!(output.contains("anyone can read it. [Got it](tx:acknowledge_notice)"));
+
+        client.acknowledge_notice(&user);
+        assert!(client.notice_seen(&user));
+        let output = to_str(&client.render(&Some(path), &Some(user)));
+        assert!(!output.contains("acknowledge_notice"));
+    }
+
+    #[test]
+    fn test_clear_completed() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(TodoContract, ());
+        let client = TodoContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        for _ in 0..4 {
+            client.add_task(&String::from_str(&env, "Task"), &None, &0, &user);
+        }
+
+        // No completed tasks, no button
+        let output = client.render(&Some(String::from_str(&env, "/tasks")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(!output_str.contains("clear_completed"));
+
+        client.complete_task(&1, &user);
+        client.complete_task(&3, &user);
+
+        let output = client.render(&Some(String::from_str(&env, "/tasks")), &Some(user.clone()));
+        let output_str = to_str(&output);
+        assert!(output_str.contains("[Clear completed](tx:clear_completed {})"));
+
+        client.clear_completed(&user);
+        let remaining = client.get_tasks(&user);
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(remaining.get(0).unwrap().id, 2);
+        assert_eq!(remaining.get(1).unwrap().id, 4);
+        assert_eq!(client.get_stats().0, 2);
+        assert_eq!(client.get_completed_count(), 0);
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000_000);
+
+        let cases: [(u64, &[u8]); 8] = [
+            (1_000_000, b"just now"),
+            (999_970, b"just now"),
+            (999_940, b"1 minute ago"),
+            (1_000_000 - 3 * 60, b"3 minutes ago"),
+            (1_000_000 - 2 * 3_600, b"2 hours ago"),
+            (1_000_000 - 5 * 86_400, b"5 days ago"),
+            (1_000_000 + 10 * 60, b"in 10 minutes"),
+            (1_000_000 + 86_400, b"in 1 day"),
+        ];
+        for (ts, expected) in cases {
+            assert_eq!(relative_time(&env, ts), Bytes::from_slice(&env, expected));
+        }
+    }
 }
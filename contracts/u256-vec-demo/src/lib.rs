@@ -15,6 +15,77 @@ use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String, Vec, U256
 
 soroban_render!(markdown, styles);
 
+/// Section headings, in page order. The table of contents is built from these.
+const SECTIONS: [&str; 8] = [
+    "1. Creating and Adding Elements",
+    "2. Iteration (Primary Access Pattern)",
+    "3. Index-Based Access",
+    "4. Finding Elements",
+    "5. Transforming Elements",
+    "6. Counting with Conditions",
+    "7. Division, Remainder, and Shifts",
+    "Summary",
+];
+
+/// Derive an anchor slug from heading text: lowercase, spaces to hyphens,
+/// punctuation stripped. "2. Iteration (Primary)" becomes "2-iteration-primary".
+fn slugify(env: &Env, text: &str) -> Bytes {
+    let mut out = Bytes::new(env);
+    for b in text.bytes() {
+        if b.is_ascii_alphanumeric() {
+            out.push_back(b.to_ascii_lowercase());
+        } else if b == b' ' || b == b'-' {
+            out.push_back(b'-');
+        }
+    }
+    out
+}
+
+/// Anchor target placed just before a heading so TOC links can jump to it
+fn anchor(env: &Env, heading: &str) -> Bytes {
+    let mut out = Bytes::from_slice(env, b"<a id=\"");
+    out.append(&slugify(env, heading));
+    out.extend_from_slice(b"\"></a>\n\n");
+    out
+}
+
+/// Table of contents: one `- [Heading](#slug)` item per heading
+fn toc(env: &Env, headings: &[&str]) -> Bytes {
+    let mut out = Bytes::new(env);
+    for heading in headings {
+        out.extend_from_slice(b"- [");
+        out.extend_from_slice(heading.as_bytes());
+        out.extend_from_slice(b"](#");
+        out.append(&slugify(env, heading));
+        out.extend_from_slice(b")\n");
+    }
+    out.extend_from_slice(b"\n");
+    out
+}
+
+/// Open a collapsible `<details>` section. `open` sets whether it starts expanded.
+/// The summary is HTML-escaped since it sits inside a raw HTML block.
+fn details_start(env: &Env, summary: &str, open: bool) -> Bytes {
+    let tag: &[u8] = if open { b"<details open>" } else { b"<details>" };
+    let mut out = Bytes::from_slice(env, tag);
+    out.extend_from_slice(b"\n<summary>");
+    for b in summary.as_bytes() {
+        match b {
+            b'<' => out.extend_from_slice(b"&lt;"),
+            b'>' => out.extend_from_slice(b"&gt;"),
+            b'&' => out.extend_from_slice(b"&amp;"),
+            _ => out.push_back(*b),
+        }
+    }
+    out.extend_from_slice(b"</summary>\n\n");
+    out
+}
+
+/// Close a section opened with `details_start`
+fn details_end(env: &Env) -> Bytes {
+    Bytes::from_slice(env, b"\n</details>\n\n")
+}
+
 #[contract]
 pub struct U256VecDemo;
 
@@ -56,13 +127,17 @@ impl U256VecDemo {
             .div_start("note")
             .text("Soroban's Vec is not Rust's standard Vec. It's a handle to data in the Soroban host environment, so you cannot convert it to a slice. This tutorial shows the patterns you need.")
             .div_end()
-            .newline();
+            .newline()
+            .h3("Contents")
+            .raw(toc(&env, &SECTIONS));
 
         // =====================================================================
         // Section 1: Creating and Adding Elements
         // =====================================================================
         md = md
-            .h2("1. Creating and Adding Elements")
+            .raw(anchor(&env, SECTIONS[0]))
+            .h2(SECTIONS[0])
+            .raw(details_start(&env, "Example", true))
             .raw_str("```rust\n")
             .raw_str("let mut nums: Vec<U256> = Vec::new(&env);\n")
             .raw_str("nums.push_back(U256::from_u32(&env, 100));\n")
@@ -94,7 +169,10 @@ impl U256VecDemo {
         // Section 2: Iteration (the primary access pattern)
         // =====================================================================
         md = md
-            .h2("2. Iteration (Primary Access Pattern)")
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[1]))
+            .h2(SECTIONS[1])
+            .raw(details_start(&env, "Example", false))
             .paragraph("Since you can't get a slice, iteration is how you access elements:")
             .raw_str("```rust\n")
             .raw_str("let mut total = U256::from_u32(&env, 0);\n")
@@ -119,7 +197,10 @@ impl U256VecDemo {
         // Section 3: Index-Based Access
         // =====================================================================
         md = md
-            .h2("3. Index-Based Access")
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[2]))
+            .h2(SECTIONS[2])
+            .raw(details_start(&env, "Example", false))
             .paragraph("Use get(index) which returns Option<T>:")
             .raw_str("```rust\n")
             .raw_str("for i in 0..nums.len() {\n")
@@ -153,7 +234,10 @@ impl U256VecDemo {
         // Section 4: Finding Elements (no filter/find methods)
         // =====================================================================
         md = md
-            .h2("4. Finding Elements")
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[3]))
+            .h2(SECTIONS[3])
+            .raw(details_start(&env, "Example", false))
             .paragraph("No find() or filter() - use manual loops:")
             .raw_str("```rust\n")
             .raw_str("let threshold = U256::from_u32(&env, 100);\n")
@@ -191,7 +275,10 @@ impl U256VecDemo {
         // Section 5: Transforming (no map)
         // =====================================================================
         md = md
-            .h2("5. Transforming Elements")
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[4]))
+            .h2(SECTIONS[4])
+            .raw(details_start(&env, "Example", false))
             .paragraph("No map() - build a new Vec manually:")
             .raw_str("```rust\n")
             .raw_str("let two = U256::from_u32(&env, 2);\n")
@@ -225,7 +312,10 @@ impl U256VecDemo {
         // Section 6: Counting with Conditions
         // =====================================================================
         md = md
-            .h2("6. Counting with Conditions")
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[5]))
+            .h2(SECTIONS[5])
+            .raw(details_start(&env, "Example", false))
             .raw_str("```rust\n")
             .raw_str("let mut count: u32 = 0;\n")
             .raw_str("for n in nums.iter() {\n")
@@ -256,16 +346,75 @@ impl U256VecDemo {
             .div_end()
             .newline();
 
+        // =====================================================================
+        // Section 7: Division, Remainder, and Shifts
+        // =====================================================================
+        md = md
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[6]))
+            .h2(SECTIONS[6])
+            .raw(details_start(&env, "Example", false))
+            .paragraph("Division rounds toward zero; rem_euclid() gives the remainder (there is no % operator):")
+            .raw_str("```rust\n")
+            .raw_str("let n = U256::from_u32(&env, 1000);\n")
+            .raw_str("let d = U256::from_u32(&env, 7);\n")
+            .raw_str("let quotient = n.div(&d);\n")
+            .raw_str("let remainder = n.rem_euclid(&d);\n")
+            .raw_str("let big = U256::from_u32(&env, 1).shl(128);\n")
+            .raw_str("let eighth = U256::from_u32(&env, 1024).shr(3);\n")
+            .raw_str("```\n\n");
+
+        let n = U256::from_u32(&env, 1000);
+        let d = U256::from_u32(&env, 7);
+
+        md = md
+            .div_start("result")
+            .text("1000 / 7 = ")
+            .raw(u256_to_bytes(&env, &n.div(&d)))
+            .text(" | 1000 rem 7 = ")
+            .raw(u256_to_bytes(&env, &n.rem_euclid(&d)))
+            .text(" | 1 << 128 = ")
+            .raw(u256_to_bytes(&env, &U256::from_u32(&env, 1).shl(128)))
+            .text(" | 1024 >> 3 = ")
+            .raw(u256_to_bytes(&env, &U256::from_u32(&env, 1024).shr(3)))
+            .div_end()
+            .newline();
+
+        md = md
+            .paragraph("Dividing by zero (or shifting by 256 or more bits) traps the host and aborts the whole transaction, so check untrusted divisors first:")
+            .raw_str("```rust\n")
+            .raw_str("let zero = U256::from_u32(&env, 0);\n")
+            .raw_str("let quotient = if d == zero { None } else { Some(n.div(&d)) };\n")
+            .raw_str("```\n\n");
+
+        let zero = U256::from_u32(&env, 0);
+        let divisor = U256::from_u32(&env, 0);
+        let quotient = if divisor == zero {
+            None
+        } else {
+            Some(n.div(&divisor))
+        };
+
+        md = md.div_start("note").text("1000 / 0 = ");
+        md = match quotient {
+            Some(val) => md.raw(u256_to_bytes(&env, &val)),
+            None => md.text("None (divisor was zero, division skipped)"),
+        };
+        md = md.div_end().newline();
+
         // =====================================================================
         // Summary
         // =====================================================================
         md = md
-            .h2("Summary")
+            .raw(details_end(&env))
+            .raw(anchor(&env, SECTIONS[7]))
+            .h2(SECTIONS[7])
             .list_item("Vec::new(&env) - always needs the environment")
             .list_item("push_back(val) - add elements")
             .list_item("get(i) returns Option<T> - no panicking indexing")
             .list_item("iter() - the main way to process elements")
             .list_item("No map, filter, collect - use manual loops")
+            .list_item("div, rem_euclid, shl, shr are methods - guard against a zero divisor")
             .list_item("Cannot convert to &[T] - data lives in host, not WASM memory, so data format can't support direct `as_slice()` operation")
             .newline()
             .hr()
@@ -313,4 +462,131 @@ mod tests {
         let styles = client.styles();
         assert!(!styles.is_empty());
     }
+
+    #[test]
+    fn test_details_open_flag() {
+        let env = Env::default();
+
+        let open = details_start(&env, "Example", true);
+        assert_eq!(
+            open,
+            Bytes::from_slice(&env, b"<details open>\n<summary>Example</summary>\n\n")
+        );
+
+        let closed = details_start(&env, "Example", false);
+        assert_eq!(
+            closed,
+            Bytes::from_slice(&env, b"<details>\n<summary>Example</summary>\n\n")
+        );
+    }
+
+    #[test]
+    fn test_details_brackets_content() {
+        let env = Env::default();
+
+        let result = MarkdownBuilder::new(&env)
+            .raw(details_start(&env, "Vec<U256> & friends", false))
+            .paragraph("Hidden content")
+            .raw(details_end(&env))
+            .build();
+
+        let mut buf: [u8; 256] = [0; 256];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let output = core::str::from_utf8(&buf[..len]).unwrap();
+
+        assert!(output.starts_with("<details>\n<summary>Vec&lt;U256&gt; &amp; friends</summary>\n\n"));
+        assert!(output.ends_with("</details>\n\n"));
+        let open = output.find("</summary>").unwrap();
+        let content = output.find("Hidden content").unwrap();
+        let close = output.find("</details>").unwrap();
+        assert!(open < content && content < close);
+    }
+
+    #[test]
+    fn test_toc_slugs() {
+        let env = Env::default();
+
+        let headings = ["Getting Started", "2. Iteration (Primary)", "Q&A: Vec<U256>?"];
+        let result = toc(&env, &headings);
+
+        let mut buf: [u8; 256] = [0; 256];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let output = core::str::from_utf8(&buf[..len]).unwrap();
+
+        assert!(output.contains("- [Getting Started](#getting-started)\n"));
+        assert!(output.contains("- [2. Iteration (Primary)](#2-iteration-primary)\n"));
+        assert!(output.contains("- [Q&A: Vec<U256>?](#qa-vecu256)\n"));
+        assert_eq!(
+            anchor(&env, "Getting Started"),
+            Bytes::from_slice(&env, b"<a id=\"getting-started\"></a>\n\n")
+        );
+    }
+
+    #[test]
+    fn test_division_and_shifts() {
+        let env = Env::default();
+        let contract_id = env.register(U256VecDemo, ());
+        let client = U256VecDemoClient::new(&env, &contract_id);
+
+        let result = client.render(&None, &None);
+
+        let mut buf: [u8; 16384] = [0; 16384];
+        let len = result.len() as usize;
+        for i in 0..len {
+            if let Some(b) = result.get(i as u32) {
+                buf[i] = b;
+            }
+        }
+        let output = core::str::from_utf8(&buf[..len]).unwrap();
+
+        assert!(output.contains("1000 / 7 = 142 | 1000 rem 7 = 6"));
+        assert!(output.contains("1 << 128 = 340282366920938463463374607431768211456"));
+        assert!(output.contains("1024 >> 3 = 128"));
+        assert!(output.contains("1000 / 0 = None (divisor was zero, division skipped)"));
+        assert!(output.contains("- [7. Division, Remainder, and Shifts](#7-division-remainder-and-shifts)"));
+    }
+
+    #[test]
+    fn test_u256_to_bytes_full_range() {
+        let env = Env::default();
+
+        assert_eq!(u256_to_bytes(&env, &U256::from_u32(&env, 0)), Bytes::from_slice(&env, b"0"));
+        assert_eq!(u256_to_bytes(&env, &U256::from_u32(&env, 7)), Bytes::from_slice(&env, b"7"));
+        assert_eq!(
+            u256_to_bytes(&env, &U256::from_u32(&env, u32::MAX)),
+            Bytes::from_slice(&env, b"4294967295")
+        );
+
+        // 2^200 has 61 digits, far past anything a u32/u64 digit buffer holds
+        let two = U256::from_u32(&env, 2);
+        let mut big = U256::from_u32(&env, 1);
+        for _ in 0..200 {
+            big = big.mul(&two);
+        }
+        assert_eq!(
+            u256_to_bytes(&env, &big),
+            Bytes::from_slice(&env, b"1606938044258990275541962092341162602522202993782792835301376")
+        );
+
+        // U256::MAX = (2^255 - 1) + 2^255 is the widest value at 78 digits
+        let half = big.shl(55);
+        let max = half.sub(&U256::from_u32(&env, 1)).add(&half);
+        assert_eq!(
+            u256_to_bytes(&env, &max),
+            Bytes::from_slice(
+                &env,
+                b"115792089237316195423570985008687907853269984665640564039457584007913129639935"
+            )
+        );
+    }
 }
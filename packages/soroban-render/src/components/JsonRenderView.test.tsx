@@ -327,6 +327,28 @@ describe("JsonRenderView", () => {
       expect(screen.getByRole("option", { name: "High" })).toBeInTheDocument();
     });
 
+    it("should pass number bounds and textarea rows to the inputs", () => {
+      const document: JsonUIDocument = {
+        format: "soroban-render-json-v1",
+        components: [{
+          type: "form",
+          action: "add_task",
+          fields: [
+            { name: "estimate", type: "number", label: "Estimate", min: 0, max: 40, step: 1 },
+            { name: "notes", type: "textarea", label: "Notes", rows: 3 },
+          ],
+        }],
+      };
+
+      render(<JsonRenderView document={document} />);
+
+      const estimate = screen.getByLabelText("Estimate");
+      expect(estimate).toHaveAttribute("min", "0");
+      expect(estimate).toHaveAttribute("max", "40");
+      expect(estimate).toHaveAttribute("step", "1");
+      expect(screen.getByLabelText("Notes")).toHaveAttribute("rows", "3");
+    });
+
     it("should render checkbox field", () => {
       const document: JsonUIDocument = {
         format: "soroban-render-json-v1",
@@ -732,6 +754,26 @@ describe("JsonRenderView", () => {
 
       expect(screen.getByText("Monthly Data")).toBeInTheDocument();
     });
+
+    it("should render line chart", () => {
+      const document: JsonUIDocument = {
+        format: "soroban-render-json-v1",
+        components: [{
+          type: "chart",
+          chartType: "line",
+          data: [
+            { label: "Q1", value: 10 },
+            { label: "Q2", value: 30 },
+          ],
+          title: "Supply",
+        }],
+      };
+
+      const { container } = render(<JsonRenderView document={document} />);
+
+      expect(screen.getByText("Supply")).toBeInTheDocument();
+      expect(container.querySelector("polyline")).toBeInTheDocument();
+    });
   });
 
   describe("multiple components", () => {
@@ -16,7 +16,7 @@ import {
   ChartComponent,
 } from "../parsers/json";
 import { parseMarkdown } from "../parsers/markdown";
-import { PieChart, GaugeChart, BarChart } from "./charts";
+import { PieChart, GaugeChart, BarChart, LineChart } from "./charts";
 
 export interface JsonRenderViewProps {
   document: JsonUIDocument;
@@ -229,6 +229,7 @@ function FieldRenderer({ field, value, onChange }: FieldRendererProps): React.Re
           name={field.name}
           placeholder={field.placeholder}
           required={field.required}
+          rows={field.rows}
           value={String(value || "")}
           onChange={(e) => onChange(e.target.value)}
         />
@@ -282,6 +283,9 @@ function FieldRenderer({ field, value, onChange }: FieldRendererProps): React.Re
         name={field.name}
         placeholder={field.placeholder}
         required={field.required}
+        min={field.min}
+        max={field.max}
+        step={field.step}
         value={String(value || "")}
         onChange={(e) => onChange(e.target.value)}
       />
@@ -506,6 +510,8 @@ function ChartRenderer({ component }: { component: ChartComponent }): React.Reac
       );
     case "bar":
       return <BarChart data={component.data} title={component.title} />;
+    case "line":
+      return <LineChart data={component.data} title={component.title} color={component.color} />;
     default:
       return <div>Unknown chart type</div>;
   }
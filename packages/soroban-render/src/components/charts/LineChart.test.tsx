@@ -0,0 +1,74 @@
+import React from "react";
+import { describe, it, expect } from "vitest";
+import { render, screen } from "@testing-library/react";
+import { LineChart } from "./LineChart";
+import type { ChartDataPoint } from "../../parsers/json";
+
+describe("LineChart", () => {
+  describe("empty state", () => {
+    it("should show message when data is empty", () => {
+      render(<LineChart data={[]} />);
+
+      expect(screen.getByText("No data available")).toBeInTheDocument();
+    });
+
+    it("should show message when all values are zero", () => {
+      const data: ChartDataPoint[] = [
+        { label: "A", value: 0 },
+        { label: "B", value: 0 },
+      ];
+
+      render(<LineChart data={data} />);
+
+      expect(screen.getByText("No data to display")).toBeInTheDocument();
+    });
+  });
+
+  describe("rendering", () => {
+    it("should draw one polyline through every point", () => {
+      const data: ChartDataPoint[] = [
+        { label: "Q1", value: 10 },
+        { label: "Q2", value: 30 },
+        { label: "Q3", value: 20 },
+      ];
+
+      const { container } = render(<LineChart data={data} title="Supply" />);
+
+      expect(screen.getByText("Supply")).toBeInTheDocument();
+      const polyline = container.querySelector("polyline");
+      expect(polyline?.getAttribute("points")?.split(" ")).toHaveLength(3);
+      expect(container.querySelectorAll("circle")).toHaveLength(3);
+      expect(screen.getByText("Q2")).toBeInTheDocument();
+    });
+
+    it("should include negative values in the axis", () => {
+      const data: ChartDataPoint[] = [
+        { label: "A", value: -100 },
+        { label: "B", value: 100 },
+      ];
+
+      render(<LineChart data={data} />);
+
+      expect(screen.getByText("-100")).toBeInTheDocument();
+      expect(screen.getByText("100")).toBeInTheDocument();
+    });
+  });
+
+  describe("colors", () => {
+    it("should use the line color when provided", () => {
+      const data: ChartDataPoint[] = [{ label: "A", value: 1 }];
+
+      const { container } = render(<LineChart data={data} color="#22c55e" />);
+
+      expect(container.querySelector("polyline")).toHaveAttribute("stroke", "#22c55e");
+    });
+
+    it("should use the default color when not provided", () => {
+      const data: ChartDataPoint[] = [{ label: "A", value: 1 }];
+
+      const { container } = render(<LineChart data={data} />);
+
+      expect(container.querySelector("polyline")).toHaveAttribute("stroke", "#3b82f6");
+    });
+  });
+});
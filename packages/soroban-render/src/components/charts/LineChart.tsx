@@ -0,0 +1,117 @@
+import React from "react";
+import { ChartDataPoint } from "../../parsers/json";
+
+interface LineChartProps {
+  data: ChartDataPoint[];
+  title?: string;
+  color?: string;
+  height?: number;
+}
+
+const DEFAULT_COLOR = "#3b82f6";
+
+export function LineChart({ data, title, color, height = 200 }: LineChartProps): React.ReactElement {
+  if (!data || data.length === 0) {
+    return (
+      <div className="soroban-chart soroban-chart-line" style={{ textAlign: "center", padding: "1rem" }}>
+        <p style={{ color: "#666" }}>No data available</p>
+      </div>
+    );
+  }
+
+  // The axis always includes zero so negative values sit below it
+  const maxValue = Math.max(0, ...data.map((d) => d.value));
+  const minValue = Math.min(0, ...data.map((d) => d.value));
+  const range = maxValue - minValue;
+  if (range === 0) {
+    return (
+      <div className="soroban-chart soroban-chart-line" style={{ textAlign: "center", padding: "1rem" }}>
+        <p style={{ color: "#666" }}>No data to display</p>
+      </div>
+    );
+  }
+
+  const step = Math.min(60, Math.max(20, 300 / data.length));
+  const chartWidth = Math.max(1, data.length - 1) * step;
+  const paddingLeft = 40;
+  const paddingBottom = 30;
+  const paddingTop = 20;
+  const stroke = color || DEFAULT_COLOR;
+
+  const x = (index: number) => paddingLeft + index * step + step / 2;
+  const y = (value: number) => paddingTop + height * ((maxValue - value) / range);
+  const points = data.map((point, index) => `${x(index)},${y(point.value)}`).join(" ");
+
+  return (
+    <div className="soroban-chart soroban-chart-line">
+      {title && (
+        <h4 style={{ margin: "0 0 0.75rem 0", fontSize: "1rem", fontWeight: 600, textAlign: "center" }}>
+          {title}
+        </h4>
+      )}
+      <div style={{ overflowX: "auto" }}>
+        <svg
+          width={chartWidth + step + paddingLeft + 20}
+          height={height + paddingBottom + paddingTop}
+          viewBox={`0 0 ${chartWidth + step + paddingLeft + 20} ${height + paddingBottom + paddingTop}`}
+        >
+          {/* Y-axis */}
+          <line
+            x1={paddingLeft}
+            y1={paddingTop}
+            x2={paddingLeft}
+            y2={height + paddingTop}
+            stroke="#e5e7eb"
+            strokeWidth="1"
+          />
+
+          {/* Zero line */}
+          <line
+            x1={paddingLeft}
+            y1={y(0)}
+            x2={chartWidth + step + paddingLeft}
+            y2={y(0)}
+            stroke="#e5e7eb"
+            strokeWidth="1"
+          />
+
+          {/* Y-axis labels */}
+          {[0, 0.25, 0.5, 0.75, 1].map((fraction, index) => {
+            const value = Math.round(minValue + range * fraction);
+            const labelY = paddingTop + height * (1 - fraction);
+            return (
+              <g key={index}>
+                <line x1={paddingLeft - 5} y1={labelY} x2={paddingLeft} y2={labelY} stroke="#e5e7eb" />
+                <text x={paddingLeft - 8} y={labelY + 4} textAnchor="end" fontSize="10" fill="#6b7280">
+                  {value}
+                </text>
+              </g>
+            );
+          })}
+
+          <polyline points={points} fill="none" stroke={stroke} strokeWidth="2" />
+
+          {/* Points */}
+          {data.map((point, index) => (
+            <g key={index}>
+              <circle cx={x(index)} cy={y(point.value)} r="3" fill={stroke}>
+                <title>{`${point.label}: ${point.value}`}</title>
+              </circle>
+
+              {/* X-axis label */}
+              <text
+                x={x(index)}
+                y={height + paddingTop + 15}
+                textAnchor="middle"
+                fontSize="10"
+                fill="#6b7280"
+              >
+                {point.label.length > 8 ? point.label.slice(0, 8) + "..." : point.label}
+              </text>
+            </g>
+          ))}
+        </svg>
+      </div>
+    </div>
+  );
+}
@@ -1,3 +1,4 @@
 export { PieChart } from "./PieChart";
 export { GaugeChart } from "./GaugeChart";
 export { BarChart } from "./BarChart";
+export { LineChart } from "./LineChart";
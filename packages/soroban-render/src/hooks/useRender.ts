@@ -15,6 +15,7 @@ import { loadRenderContinuations, hasRenderContinuations } from "../utils/render
 import { parseMeta, applyMetaToDocument } from "../parsers/meta";
 import { parseErrors } from "../parsers/errors";
 import { parseAliases } from "../parsers/aliases";
+import { parseMarkers, hasMarkers } from "../parsers/markers";
 
 export interface UseRenderResult {
   html: string | null;
@@ -253,6 +254,10 @@ export function useRender(
           progressiveTagsRef.current = [];
         }
 
+        if (hasMarkers(processedContent)) {
+          processedContent = parseMarkers(processedContent).content;
+        }
+
         const renderedHtml = await parseMarkdown(processedContent);
         setHtml(renderedHtml);
         setJsonDocument(null);
@@ -261,6 +266,8 @@ export function useRender(
         if (shouldResolveProgressive && progressiveTags.length > 0) {
           // Track loaded chunks for continuation tags (accumulate content)
           const continuationContent: Map<string, string[]> = new Map();
+          // Raw markdown of chunks continuing a table, re-parsed together
+          const continuationRows: Map<string, string[]> = new Map();
 
           // Initialize continuation content arrays
           for (const tag of progressiveTags) {
@@ -293,12 +300,17 @@ export function useRender(
                 processedChunk = resolved.content;
               }
 
+              if (hasMarkers(processedChunk)) {
+                processedChunk = parseMarkers(processedChunk).content;
+              }
+
               // Parse the chunk as markdown
               const chunkHtml = await parseMarkdown(processedChunk);
 
               // Check if this belongs to a continuation tag
               let belongsToContinuation = false;
               let continuationKey = "";
+              let tableHeader: string | undefined;
 
               for (const tag of progressiveTags) {
                 if (tag.type === "continue") {
@@ -309,6 +321,7 @@ export function useRender(
                     if (index >= from && (total === undefined || index < total)) {
                       belongsToContinuation = true;
                       continuationKey = `continue-${collection}-${from}`;
+                      tableHeader = contTag.tableHeader;
                       break;
                     }
                   }
@@ -321,12 +334,24 @@ export function useRender(
                 chunks[index] = chunkHtml; // Use index for ordering
                 continuationContent.set(continuationKey, chunks);
 
+                // Table rows only parse as a table under their header, so
+                // rows continuing a table are re-parsed together
+                let tableHtml: string | undefined;
+                if (tableHeader !== undefined) {
+                  const rows = continuationRows.get(continuationKey) || [];
+                  rows[index] = processedChunk.trimEnd();
+                  continuationRows.set(continuationKey, rows);
+                  tableHtml = await parseMarkdown(
+                    `${tableHeader}\n${rows.filter(Boolean).join("\n")}\n`
+                  );
+                }
+
                 // Update HTML by replacing continuation wrapper with new content
                 setHtml((prevHtml) => {
                   if (!prevHtml) return prevHtml;
 
                   // Join all loaded chunks in order
-                  const orderedContent = chunks.filter(Boolean).join("\n");
+                  const orderedContent = tableHtml ?? chunks.filter(Boolean).join("\n");
 
                   // Build a wrapper that can be found again for subsequent updates
                   const newWrapper = `<div class="soroban-progressive-loaded" data-progressive-id="${continuationKey}">${orderedContent}</div>`;
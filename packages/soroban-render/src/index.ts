@@ -47,6 +47,7 @@ export {
   type PieChartComponent,
   type GaugeChartComponent,
   type BarChartComponent,
+  type LineChartComponent,
   type FormField,
   type ParseJsonResult,
 } from "./parsers/json";
@@ -54,6 +55,7 @@ export {
 export { PieChart } from "./components/charts/PieChart";
 export { GaugeChart } from "./components/charts/GaugeChart";
 export { BarChart } from "./components/charts/BarChart";
+export { LineChart } from "./components/charts/LineChart";
 
 export {
   useRender,
@@ -155,6 +157,13 @@ export {
   type ParsedProgressiveContent,
 } from "./parsers/continuation";
 
+export {
+  parseMarkers,
+  hasMarkers,
+  type MarkerTag,
+  type ParsedMarkers,
+} from "./parsers/markers";
+
 export {
   loadRenderContinuations,
   hasRenderContinuations,
@@ -30,6 +30,42 @@ describe("parseProgressiveTags", () => {
     expect((result.tags[0] as any).total).toBeUndefined();
   });
 
+  it("should parse continuation tag with total and size", () => {
+    const content = '{{continue collection="comments" from=5 total=15 size=812}}';
+    const result = parseProgressiveTags(content);
+
+    expect(result.tags).toHaveLength(1);
+    const tag = result.tags[0] as any;
+    expect(tag.from).toBe(5);
+    expect(tag.total).toBe(15);
+    expect(tag.size).toBe(812);
+    expect(result.content).toContain('data-from="5" data-total="15" data-size="812"');
+  });
+
+  it("should omit total and size attributes when absent", () => {
+    const result = parseProgressiveTags('{{continue collection="data" from=10}}');
+
+    expect((result.tags[0] as any).size).toBeUndefined();
+    expect(result.content).not.toContain("data-total");
+    expect(result.content).not.toContain("data-size");
+  });
+
+  it("should record the header of a table the tag continues", () => {
+    const content =
+      "Intro\n\n| # | Task |\n|---|---|\n| 1 | One |\n" +
+      '{{continue collection="rows" from=1 total=5}}\n';
+    const result = parseProgressiveTags(content);
+
+    expect((result.tags[0] as any).tableHeader).toBe("| # | Task |\n|---|---|");
+  });
+
+  it("should not record a table header after a blank line", () => {
+    const content = '| # | Task |\n|---|---|\n| 1 | One |\n\n{{continue collection="rows" from=1}}';
+    const result = parseProgressiveTags(content);
+
+    expect((result.tags[0] as any).tableHeader).toBeUndefined();
+  });
+
   it("should parse paginated continuation tag", () => {
     const content = '{{continue collection="items" page=2 per_page=10 total=47}}';
     const result = parseProgressiveTags(content);
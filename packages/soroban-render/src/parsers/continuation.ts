@@ -21,6 +21,14 @@ export interface ContinuationTag {
   perPage?: number;
   /** Total number of chunks/items */
   total?: number;
+  /** Total size of the collection in bytes */
+  size?: number;
+  /**
+   * Header and delimiter rows of the table the tag sits directly below, if
+   * any. Chunks loaded for the tag are table rows and are rendered under
+   * this header.
+   */
+  tableHeader?: string;
   /** Position in the original content string */
   position: number;
   /** Length of the original tag in the content */
@@ -72,8 +80,9 @@ export interface ParsedProgressiveContent {
 // Regex patterns for parsing tags
 // {{continue collection="name" from=N total=T}}
 // {{continue collection="name" page=N per_page=M total=T}}
+// {{continue collection="name" from=N total=T size=S}}
 const CONTINUE_PATTERN =
-  /\{\{continue\s+collection="([^"]+)"(?:\s+from=(\d+))?(?:\s+page=(\d+))?(?:\s+per_page=(\d+))?(?:\s+total=(\d+))?\s*\}\}/g;
+  /\{\{continue\s+collection="([^"]+)"(?:\s+from=(\d+))?(?:\s+page=(\d+))?(?:\s+per_page=(\d+))?(?:\s+total=(\d+))?(?:\s+size=(\d+))?\s*\}\}/g;
 
 // {{chunk collection="name" index=N}}
 // {{chunk collection="name" index=N placeholder="..."}}
@@ -84,6 +93,27 @@ const CHUNK_PATTERN =
 // Used for waterfall loading of additional rendered content
 const RENDER_PATTERN = /\{\{render\s+path="([^"]+)"\s*\}\}/g;
 
+/**
+ * The header and delimiter rows of the markdown table ending right before
+ * `position`, or undefined when the preceding line isn't a table row.
+ */
+function tableHeaderBefore(content: string, position: number): string | undefined {
+  const lines = content.slice(0, position).split("\n");
+  // The tag starts a line, so the last piece is empty
+  if (lines.pop() !== "") return undefined;
+
+  let start = lines.length;
+  while (start > 0 && lines[start - 1]!.trimStart().startsWith("|")) {
+    start--;
+  }
+  if (lines.length - start < 2) return undefined;
+
+  const header = lines[start]!;
+  const delimiter = lines[start + 1]!;
+  if (!/^\s*\|(\s*:?-+:?\s*\|)+\s*$/.test(delimiter)) return undefined;
+  return `${header}\n${delimiter}`;
+}
+
 /**
  * Build a placeholder div HTML string for a progressive tag.
  */
@@ -97,8 +127,12 @@ function buildPlaceholderDiv(tag: ProgressiveTag, id: string): string {
     case "render":
       return `<div class="${baseClass} soroban-render-continuation" data-progressive-id="${id}" data-type="render" data-path="${tag.path}"></div>`;
 
-    case "continue":
-      return `<div class="${baseClass}" data-progressive-id="${id}" data-type="continue" data-collection="${tag.collection}" data-from="${tag.from ?? 0}"></div>`;
+    case "continue": {
+      // Known totals let the viewer show "5 of 15 loaded" before fetching
+      const total = tag.total !== undefined ? ` data-total="${tag.total}"` : "";
+      const size = tag.size !== undefined ? ` data-size="${tag.size}"` : "";
+      return `<div class="${baseClass}" data-progressive-id="${id}" data-type="continue" data-collection="${tag.collection}" data-from="${tag.from ?? 0}"${total}${size}></div>`;
+    }
   }
 }
 
@@ -125,6 +159,8 @@ export function parseProgressiveTags(content: string): ParsedProgressiveContent
       page: match[3] ? parseInt(match[3], 10) : undefined,
       perPage: match[4] ? parseInt(match[4], 10) : undefined,
       total: match[5] ? parseInt(match[5], 10) : undefined,
+      size: match[6] ? parseInt(match[6], 10) : undefined,
+      tableHeader: tableHeaderBefore(content, match.index),
       position: match.index,
       length: match[0].length,
     });
@@ -86,6 +86,25 @@ describe("parseIncludes", () => {
     expect(result.includes[0].func).toBe("header");
   });
 
+  it("should forward an args JSON object as the path", () => {
+    const content =
+      'before {{include contract=CABC123 func="header" args="{&quot;title&quot;:&quot;A &amp; B&quot;}"}} after';
+    const result = parseIncludes(content);
+
+    expect(result.includes).toHaveLength(1);
+    expect(result.includes[0].func).toBe("header");
+    expect(result.includes[0].path).toBe('{"title":"A & B"}');
+    expect(result.includes[0].params).toEqual({});
+    expect(content.slice(result.includes[0].endIndex)).toBe(" after");
+  });
+
+  it("should prefer an explicit path over args", () => {
+    const content = '{{include contract=CABC123 path="/tasks" args="{}"}}';
+    const result = parseIncludes(content);
+
+    expect(result.includes[0].path).toBe("/tasks");
+  });
+
   it("should skip includes without contract attribute", () => {
     const content = '{{include func="header"}}';
     const result = parseIncludes(content);
@@ -16,6 +16,9 @@
  *   - contract: Contract ID, "SELF", or alias (e.g., "@main")
  *   - func: Optional function name (calls render_{func} instead of render)
  *   - path: Optional path argument (legacy mode only)
+ *   - args: Optional JSON object, forwarded as the path when no path is given.
+ *     Quotes and ampersands are entity-escaped (&quot; and &amp;), e.g.
+ *     args="{&quot;title&quot;:&quot;Tasks&quot;}" → path {"title":"Tasks"}
  *   - viewer: Flag (no value) - passes current viewer address as parameter
  *   - Any other attributes become named parameters passed to the function
  *
@@ -86,8 +89,9 @@ export interface ParsedIncludes {
 }
 
 // Regex to match {{include ...}} tags
-// Captures the full match and allows for various attribute formats
-const INCLUDE_REGEX = /\{\{include\s+([^}]+)\}\}/g;
+// Captures the full match and allows for various attribute formats.
+// Quoted values may contain "}" so that args can carry a JSON object.
+const INCLUDE_REGEX = /\{\{include\s+((?:"[^"]*"|'[^']*'|[^}"'])+)\}\}/g;
 
 // Regex to parse individual attributes
 // Handles: key=value, key="value", key='value', or standalone key (flag)
@@ -99,7 +103,14 @@ const INCLUDE_REGEX = /\{\{include\s+([^}]+)\}\}/g;
 const ATTR_REGEX = /(\w+)(?:=(?:"([^"]*)"|'([^']*)'|(\S+)))?/g;
 
 // Standard attributes that are not passed as params
-const STANDARD_ATTRS = new Set(["contract", "func", "path"]);
+const STANDARD_ATTRS = new Set(["contract", "func", "path", "args"]);
+
+/**
+ * Decode the entity escaping used for JSON in an args attribute.
+ */
+function decodeArgs(value: string): string {
+  return value.replace(/&quot;/g, '"').replace(/&amp;/g, "&");
+}
 
 /**
  * Parse a content string to find all {{include ...}} tags.
@@ -127,7 +138,9 @@ export function parseIncludes(content: string): ParsedIncludes {
 
     // Extract standard attributes (must be strings, not flags)
     const funcAttr = attrs.func;
-    const pathAttr = attrs.path;
+    const argsAttr = attrs.args;
+    const pathAttr =
+      attrs.path ?? (typeof argsAttr === "string" ? decodeArgs(argsAttr) : undefined);
 
     // Build params from non-standard attributes
     const params: Record<string, string | true> = {};
@@ -275,6 +275,28 @@ describe("parseJsonUI", () => {
 
       expect(result.success).toBe(true);
     });
+
+    it("should parse line chart component", () => {
+      const result = parseJsonUI(
+        JSON.stringify({
+          format: "soroban-render-json-v1",
+          components: [
+            {
+              type: "chart",
+              chartType: "line",
+              title: "Supply",
+              data: [
+                { label: "Q1", value: 10 },
+                { label: "Q2", value: 30 },
+              ],
+              color: "#22c55e",
+            },
+          ],
+        })
+      );
+
+      expect(result.success).toBe(true);
+    });
   });
 
   describe("invalid documents", () => {
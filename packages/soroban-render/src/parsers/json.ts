@@ -77,6 +77,10 @@ export interface FormField {
   value?: string;
   checked?: boolean;
   options?: { value: string; label: string }[];
+  min?: number; // number fields
+  max?: number;
+  step?: number;
+  rows?: number; // textarea fields
 }
 
 export interface FormComponent {
@@ -176,7 +180,19 @@ export interface BarChartComponent {
   title?: string;
 }
 
-export type ChartComponent = PieChartComponent | GaugeChartComponent | BarChartComponent;
+export interface LineChartComponent {
+  type: "chart";
+  chartType: "line";
+  data: ChartDataPoint[];
+  title?: string;
+  color?: string;
+}
+
+export type ChartComponent =
+  | PieChartComponent
+  | GaugeChartComponent
+  | BarChartComponent
+  | LineChartComponent;
 
 export interface ParseJsonResult {
   success: boolean;
@@ -343,8 +359,8 @@ function validateComponents(components: unknown[]): string | null {
         break;
 
       case "chart":
-        if (!["pie", "gauge", "bar"].includes(component.chartType as string)) {
-          return `Chart at index ${i} has invalid 'chartType' (must be 'pie', 'gauge', or 'bar')`;
+        if (!["pie", "gauge", "bar", "line"].includes(component.chartType as string)) {
+          return `Chart at index ${i} has invalid 'chartType' (must be 'pie', 'gauge', 'bar', or 'line')`;
         }
         if (component.chartType === "gauge") {
           if (typeof component.value !== "number") {
@@ -354,7 +370,7 @@ function validateComponents(components: unknown[]): string | null {
             return `Gauge chart at index ${i} is missing 'max' field`;
           }
         } else {
-          // pie or bar
+          // pie, bar or line
           if (!Array.isArray(component.data)) {
             return `${component.chartType} chart at index ${i} is missing 'data' array`;
           }
@@ -162,6 +162,31 @@ describe("parseMarkdown", () => {
     });
   });
 
+  describe("collapsible sections", () => {
+    it("should preserve details and summary elements", async () => {
+      const html = await parseMarkdown(
+        "<details>\n<summary>More</summary>\n\nHidden content\n\n</details>"
+      );
+      expect(html).toContain("<details>");
+      expect(html).toContain("<summary>More</summary>");
+      expect(html).toContain("Hidden content");
+    });
+
+    it("should preserve the open attribute", async () => {
+      const html = await parseMarkdown(
+        "<details open>\n<summary>More</summary>\n\nShown\n\n</details>"
+      );
+      expect(html).toContain("<details open");
+    });
+  });
+
+  describe("inline elements", () => {
+    it("should preserve kbd elements", async () => {
+      const html = await parseMarkdown("Press <kbd>Ctrl+K</kbd> to search");
+      expect(html).toContain("<kbd>Ctrl+K</kbd>");
+    });
+  });
+
   describe("GFM features", () => {
     it("should parse task list items", async () => {
       const html = await parseMarkdown("- [ ] unchecked\n- [x] checked");
@@ -176,10 +176,11 @@ export async function parseMarkdown(markdown: string): Promise<string> {
         "p", "br", "hr",
         "ul", "ol", "li",
         "blockquote", "pre", "code",
-        "a", "strong", "em", "del", "s",
+        "a", "strong", "em", "del", "s", "kbd",
         "table", "thead", "tbody", "tr", "th", "td",
         "img",
         "div", "span",
+        "details", "summary",
         "input", "select", "option", "button", "label", "textarea",
       ],
       ALLOWED_ATTR: [
@@ -187,9 +188,11 @@ export async function parseMarkdown(markdown: string): Promise<string> {
         "target", "rel",
         "name", "type", "placeholder", "value", "required", "disabled",
         "checked", "selected", "readonly", "maxlength", "minlength",
+        "open",  // details initial state
         "min", "max", "step", "pattern", "for",
         "rows", "cols", "size", "wrap",  // textarea/input sizing
         "style",  // inline styling
+        "role",  // marker placeholders such as spinners and rings
         "data-*",
       ],
       ADD_ATTR: ["target", "rel"],
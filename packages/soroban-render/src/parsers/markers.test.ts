@@ -0,0 +1,101 @@
+import { describe, it, expect } from "vitest";
+import { parseMarkers, hasMarkers } from "./markers";
+
+describe("parseMarkers", () => {
+  it("should replace a skeleton marker with placeholder lines", () => {
+    const result = parseMarkers("Loading\n{{skeleton lines=3}}");
+
+    expect(result.markers).toHaveLength(1);
+    expect(result.markers[0]!.name).toBe("skeleton");
+    expect(result.markers[0]!.attrs.lines).toBe("3");
+    expect(result.content).toBe(
+      'Loading\n<div class="soroban-skeleton" data-lines="3">' +
+        '<div class="soroban-skeleton-line"></div>'.repeat(3) +
+        "</div>"
+    );
+  });
+
+  it("should cap the skeleton line count", () => {
+    const result = parseMarkers("{{skeleton lines=1000}}");
+    expect(result.content).toContain('data-lines="50"');
+  });
+
+  it("should decode and re-escape the oracle feed", () => {
+    const result = parseMarkers('Price: {{oracle feed="A&amp;B"}}');
+
+    expect(result.markers[0]!.attrs.feed).toBe("A&B");
+    expect(result.content).toBe(
+      'Price: <span class="soroban-oracle" data-feed="A&amp;B">A&amp;B</span>'
+    );
+  });
+
+  it("should URL-decode the share text and url", () => {
+    const result = parseMarkers(
+      '{{share label="Share" text="My%20list%20%26%20more" url="https%3A%2F%2Fexample.com%2F%3Fid%3D1"}}'
+    );
+
+    expect(result.content).toBe(
+      '<a href="#" class="soroban-share" data-text="My list &amp; more" ' +
+        'data-url="https://example.com/?id=1">Share</a>'
+    );
+  });
+
+  it("should decode the call arguments", () => {
+    const result = parseMarkers(
+      '{{call contract="SELF" func="balance" args="%7B%22id%22%3A1%7D"}}'
+    );
+
+    expect(result.content).toBe(
+      '<span class="soroban-call" data-contract="SELF" data-func="balance" ' +
+        'data-args="{&quot;id&quot;:1}"></span>'
+    );
+  });
+
+  it("should list chart slices with decoded labels", () => {
+    const result = parseMarkers('{{chart type="pie" data="Done:5,Not%20started:2"}}');
+
+    expect(result.content).toBe(
+      '<div class="soroban-chart" data-chart-type="pie" data-chart-data="Done:5,Not%20started:2">' +
+        "<ul><li>Done: 5</li><li>Not started: 2</li></ul></div>"
+    );
+  });
+
+  it("should render a spinner with its label", () => {
+    const result = parseMarkers('{{spinner label="Saving &quot;notes&quot;"}}');
+
+    expect(result.markers[0]!.attrs.label).toBe('Saving "notes"');
+    expect(result.content).toBe(
+      '<span class="soroban-spinner" role="status" aria-label="Saving &quot;notes&quot;">' +
+        "Saving &quot;notes&quot;</span>"
+    );
+  });
+
+  it("should render a completion ring", () => {
+    const result = parseMarkers("Done: {{ring percent=80}}");
+
+    expect(result.markers[0]!.attrs.percent).toBe("80");
+    expect(result.content).toBe(
+      'Done: <span class="soroban-ring" role="progressbar" aria-valuemin="0" aria-valuemax="100" ' +
+        'aria-valuenow="80" style="--percent: 80">80%</span>'
+    );
+  });
+
+  it("should clamp the ring percentage", () => {
+    expect(parseMarkers("{{ring percent=250}}").content).toContain('aria-valuenow="100"');
+  });
+
+  it("should leave unknown and include markers alone", () => {
+    const content = '{{include contract=SELF func="header"}} {{unknown a="b"}}';
+    const result = parseMarkers(content);
+
+    expect(result.markers).toHaveLength(0);
+    expect(result.content).toBe(content);
+  });
+});
+
+describe("hasMarkers", () => {
+  it("should detect markers", () => {
+    expect(hasMarkers("{{skeleton lines=2}}")).toBe(true);
+    expect(hasMarkers("plain text")).toBe(false);
+  });
+});
@@ -0,0 +1,210 @@
+/**
+ * Parser for viewer-side markers that have no markdown equivalent.
+ *
+ * Contracts emit these with the common crate's `Markers` helpers, e.g.:
+ *   {{skeleton lines=3}}
+ *   {{oracle feed="XLM/USD"}}
+ *   {{share label="Share" text="My%20list" url="https%3A%2F%2Fexample.com"}}
+ *   {{call contract="SELF" func="balance" args="%7B%7D"}}
+ *   {{chart type="pie" data="Done:5,Not%20started:2"}}
+ *   {{spinner label="Saving"}}
+ *   {{ring percent=80}}
+ *
+ * String attributes are quoted and HTML-escaped (some are also
+ * URL-encoded); numbers are bare. Each marker is replaced with a
+ * placeholder element before markdown conversion.
+ */
+
+export interface MarkerTag {
+  /** Marker name, e.g. "skeleton" */
+  name: string;
+  /** Attributes with HTML entities decoded */
+  attrs: Record<string, string>;
+  /** Position in the original content string */
+  position: number;
+  /** Length of the original tag in the content */
+  length: number;
+}
+
+export interface ParsedMarkers {
+  /** Content with markers replaced by placeholder elements */
+  content: string;
+  /** All markers found, in content order */
+  markers: MarkerTag[];
+}
+
+const MARKER_NAMES = ["skeleton", "oracle", "share", "call", "chart", "spinner", "ring"];
+
+// {{name attr="value" attr=N ...}}
+const MARKER_PATTERN = new RegExp(
+  `\\{\\{(${MARKER_NAMES.join("|")})((?:\\s+[a-z_]+=(?:"[^"]*"|\\d+))*)\\s*\\}\\}`,
+  "g"
+);
+
+const ATTR_PATTERN = /([a-z_]+)=(?:"([^"]*)"|(\d+))/g;
+
+function decodeEntities(value: string): string {
+  return value
+    .replace(/&quot;/g, '"')
+    .replace(/&#39;/g, "'")
+    .replace(/&lt;/g, "<")
+    .replace(/&gt;/g, ">")
+    .replace(/&amp;/g, "&");
+}
+
+function escapeHtml(value: string): string {
+  return value
+    .replace(/&/g, "&amp;")
+    .replace(/"/g, "&quot;")
+    .replace(/'/g, "&#39;")
+    .replace(/</g, "&lt;")
+    .replace(/>/g, "&gt;");
+}
+
+function parseAttrs(source: string): Record<string, string> {
+  const attrs: Record<string, string> = {};
+  ATTR_PATTERN.lastIndex = 0;
+  let match: RegExpExecArray | null;
+  while ((match = ATTR_PATTERN.exec(source)) !== null) {
+    const name = match[1];
+    if (!name) continue;
+    attrs[name] = match[2] !== undefined ? decodeEntities(match[2]) : (match[3] ?? "");
+  }
+  return attrs;
+}
+
+/**
+ * Decode a URL-encoded attribute, keeping the raw value if it is malformed.
+ */
+function urlAttr(attrs: Record<string, string>, name: string): string {
+  const value = attrs[name] ?? "";
+  try {
+    return decodeURIComponent(value);
+  } catch {
+    return value;
+  }
+}
+
+/**
+ * Split a chart's `label:value,...` data into slices. Labels are
+ * URL-encoded, so `:` and `,` only ever separate.
+ */
+function chartSlices(data: string): Array<{ label: string; value: number }> {
+  if (!data) return [];
+  return data.split(",").map((slice) => {
+    const sep = slice.lastIndexOf(":");
+    const label = sep < 0 ? slice : slice.slice(0, sep);
+    const value = sep < 0 ? NaN : Number(slice.slice(sep + 1));
+    let decoded = label;
+    try {
+      decoded = decodeURIComponent(label);
+    } catch {
+      // keep the raw label
+    }
+    return { label: decoded, value: Number.isFinite(value) ? value : 0 };
+  });
+}
+
+/**
+ * Parse a bare numeric attribute, falling back to `fallback`.
+ */
+function numberAttr(attrs: Record<string, string>, name: string, fallback: number): number {
+  const value = parseInt(attrs[name] ?? "", 10);
+  return Number.isNaN(value) ? fallback : value;
+}
+
+/**
+ * Build the placeholder HTML for a marker.
+ */
+function buildMarkerHtml(tag: MarkerTag): string {
+  const attrs = tag.attrs;
+
+  switch (tag.name) {
+    case "skeleton": {
+      // Cap the line count so a bad value can't flood the page
+      const lines = Math.min(numberAttr(attrs, "lines", 1), 50);
+      const rows = '<div class="soroban-skeleton-line"></div>'.repeat(lines);
+      return `<div class="soroban-skeleton" data-lines="${lines}">${rows}</div>`;
+    }
+    case "oracle": {
+      // Shows the feed name until the viewer fills in the value
+      const feed = escapeHtml(attrs.feed ?? "");
+      return `<span class="soroban-oracle" data-feed="${feed}">${feed}</span>`;
+    }
+    case "share": {
+      const label = escapeHtml(attrs.label ?? "Share");
+      const text = escapeHtml(urlAttr(attrs, "text"));
+      const url = escapeHtml(urlAttr(attrs, "url"));
+      return `<a href="#" class="soroban-share" data-text="${text}" data-url="${url}">${label}</a>`;
+    }
+    case "call": {
+      // Filled in by the host app, which simulates the read-only call
+      const contract = escapeHtml(attrs.contract ?? "");
+      const func = escapeHtml(attrs.func ?? "");
+      const args = escapeHtml(urlAttr(attrs, "args") || "{}");
+      return `<span class="soroban-call" data-contract="${contract}" data-func="${func}" data-args="${args}"></span>`;
+    }
+    case "chart": {
+      // A plain list of slices, which the host app can draw as a chart
+      const type = escapeHtml(attrs.type ?? "pie");
+      const items = chartSlices(attrs.data ?? "")
+        .map(({ label, value }) => `<li>${escapeHtml(label)}: ${value}</li>`)
+        .join("");
+      return `<div class="soroban-chart" data-chart-type="${type}" data-chart-data="${escapeHtml(attrs.data ?? "")}"><ul>${items}</ul></div>`;
+    }
+    case "spinner": {
+      // Animated by the viewer's styles while the transaction confirms
+      const label = escapeHtml(attrs.label ?? "");
+      return `<span class="soroban-spinner" role="status" aria-label="${label}">${label}</span>`;
+    }
+    case "ring": {
+      const percent = Math.min(Math.max(numberAttr(attrs, "percent", 0), 0), 100);
+      return `<span class="soroban-ring" role="progressbar" aria-valuemin="0" aria-valuemax="100" aria-valuenow="${percent}" style="--percent: ${percent}">${percent}%</span>`;
+    }
+    default:
+      return escapeHtml(`{{${tag.name}}}`);
+  }
+}
+
+/**
+ * Parse content for viewer markers.
+ *
+ * @param content - The markdown content to parse
+ * @returns Parsed content with markers replaced by placeholder elements
+ */
+export function parseMarkers(content: string): ParsedMarkers {
+  const markers: MarkerTag[] = [];
+
+  MARKER_PATTERN.lastIndex = 0;
+  let match: RegExpExecArray | null;
+  while ((match = MARKER_PATTERN.exec(content)) !== null) {
+    const name = match[1];
+    if (!name) continue;
+    markers.push({
+      name,
+      attrs: parseAttrs(match[2] ?? ""),
+      position: match.index,
+      length: match[0].length,
+    });
+  }
+
+  // Replace from the end so earlier positions stay valid
+  let resultContent = content;
+  for (let i = markers.length - 1; i >= 0; i--) {
+    const tag = markers[i]!;
+    resultContent =
+      resultContent.slice(0, tag.position) +
+      buildMarkerHtml(tag) +
+      resultContent.slice(tag.position + tag.length);
+  }
+
+  return { content: resultContent, markers };
+}
+
+/**
+ * Check if content has any viewer markers without full parsing.
+ */
+export function hasMarkers(content: string): boolean {
+  MARKER_PATTERN.lastIndex = 0;
+  return MARKER_PATTERN.test(content);
+}
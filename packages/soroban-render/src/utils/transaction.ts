@@ -24,7 +24,8 @@ export interface TransactionResult {
 
 // Field name patterns for type inference
 const ID_FIELD_PATTERN = /_id$/i;
-const U32_FIELD_PATTERN = /^(depth|count|index|limit|offset)$/i;
+const U32_FIELD_PATTERN = /^(id|depth|count|index|limit|offset|priority)$/i;
+const TIMESTAMP_FIELD_PATTERN = /^(due|deadline|timestamp)$/i;
 const SYMBOL_PATTERN = /^[a-zA-Z_][a-zA-Z0-9_]*$/;
 const INTEGER_PATTERN = /^[0-9]+$/;
 
@@ -36,7 +37,16 @@ function isStellarAddress(value: string): boolean {
   return value.startsWith("G") && value.length === 56;
 }
 
+function isOptionalField(key: string): boolean {
+  return TIMESTAMP_FIELD_PATTERN.test(key);
+}
+
 function convertStringToScVal(value: string, key?: string): xdr.ScVal {
+  // A blank optional field (e.g., an unset due date) -> None
+  if (key && isOptionalField(key) && value.trim() === "") {
+    return xdr.ScVal.scvVoid();
+  }
+
   // Check for Stellar address (starts with G, 56 chars)
   if (isStellarAddress(value)) {
     return nativeToScVal(value, { type: "address" });
@@ -49,6 +59,11 @@ function convertStringToScVal(value: string, key?: string): xdr.ScVal {
     return nativeToScVal(BigInt(value), { type: "u64" });
   }
 
+  // Ledger timestamp fields (e.g., due) -> u64
+  if (key && TIMESTAMP_FIELD_PATTERN.test(key) && isPureInteger) {
+    return nativeToScVal(BigInt(value), { type: "u64" });
+  }
+
   // Known numeric fields -> u32
   if (key && U32_FIELD_PATTERN.test(key) && isPureInteger) {
     return nativeToScVal(parseInt(value, 10), { type: "u32" });
@@ -75,7 +90,7 @@ function convertNumberToScVal(value: number): xdr.ScVal {
   return nativeToScVal(value, { type: "i128" });
 }
 
-function convertArgToScVal(value: unknown, key?: string): xdr.ScVal {
+export function convertArgToScVal(value: unknown, key?: string): xdr.ScVal {
   if (typeof value === "string") {
     return convertStringToScVal(value, key);
   }
@@ -95,6 +110,23 @@ function convertArgToScVal(value: unknown, key?: string): xdr.ScVal {
   return nativeToScVal(value);
 }
 
+/**
+ * Convert form or link args to the positional ScVal list for a contract call,
+ * filtering out:
+ * - underscore-prefixed metadata fields (e.g., _redirect, _csrf)
+ * - empty string values (from unfilled form inputs on the same page), except
+ *   optional fields, which are sent as void so later args keep their position
+ */
+export function buildContractArgs(args: Record<string, unknown>): xdr.ScVal[] {
+  const argsEntries = Object.entries(args).filter(([key, value]) => {
+    if (key.startsWith("_")) return false;
+    if (typeof value === "string" && value.trim() === "" && !isOptionalField(key)) return false;
+    return true;
+  });
+  console.log("[soroban-render] Args entries (after filtering):", argsEntries);
+  return argsEntries.map(([key, value]) => convertArgToScVal(value, key));
+}
+
 export async function submitTransaction(
   client: SorobanClient,
   contractId: string,
@@ -126,16 +158,7 @@ export async function submitTransaction(
     }
     const contract = new Contract(contractId);
 
-    // Convert args to ScVal array, filtering out:
-    // - underscore-prefixed metadata fields (e.g., _redirect, _csrf)
-    // - empty string values (from unfilled form inputs on the same page)
-    const argsEntries = Object.entries(params.args).filter(([key, value]) => {
-      if (key.startsWith("_")) return false;
-      if (typeof value === "string" && value.trim() === "") return false;
-      return true;
-    });
-    console.log("[soroban-render] Args entries (after filtering):", argsEntries);
-    const args = argsEntries.map(([key, value]) => convertArgToScVal(value, key));
+    const args = buildContractArgs(params.args);
     console.log("[soroban-render] Args converted to ScVal:", args.length, "args");
     const operation = contract.call(params.method, ...args);
 